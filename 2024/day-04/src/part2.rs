@@ -1,5 +1,4 @@
-use std::sync::mpsc;
-
+use aoc_utils::par::par_count;
 use aoc_utils::Grid;
 
 pub fn main(grid: &Grid<char>) -> usize {
@@ -9,32 +8,13 @@ pub fn main(grid: &Grid<char>) -> usize {
         return 0;
     }
 
-    let mut pool = aoc_utils::threadpool();
-    let (send, recv) = mpsc::channel::<()>();
-
-    pool.scoped(|scope| {
-        // Iterate 1 away from the border
-        for y in 1..(grid.height() - 1) {
-            for x in 1..(grid.width() - 1) {
-                if grid[(x, y)] == 'A' {
-                    let send = send.clone();
-                    scope.execute(move || check(grid, (x, y), send));
-                }
-            }
-        }
-    });
-
-    drop(send); // Force channel hangup for the while loop below
-
-    let mut num = 0;
-    while let Ok(()) = recv.recv() {
-        num += 1;
-    }
-
-    num
+    // Iterate 1 away from the border
+    let inner = (1..(grid.height() - 1)).flat_map(|y| (1..(grid.width() - 1)).map(move |x| (x, y)));
+    par_count(inner, |pos| grid[pos] == 'A' && check(grid, pos))
 }
 
-fn check(grid: &Grid<char>, pos: (usize, usize), channel: mpsc::Sender<()>) {
+/// Checks if there is an X-shaped pair of 'MAS's centered on the given position.
+fn check(grid: &Grid<char>, pos: (usize, usize)) -> bool {
     let (x, y) = pos;
 
     // 'MAS', starting from the top-left and going down-right, or starting from bottom right and going up-left
@@ -45,7 +25,5 @@ fn check(grid: &Grid<char>, pos: (usize, usize), channel: mpsc::Sender<()>) {
     let tr = grid[(x + 1, y - 1)] == 'M' && grid[(x - 1, y + 1)] == 'S';
 
     // If both diagonals have either possibility, we have a match.
-    if (tl || br) && (tr || bl) {
-        channel.send(()).unwrap();
-    }
+    (tl || br) && (tr || bl)
 }
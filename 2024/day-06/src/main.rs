@@ -1,9 +1,10 @@
 use std::collections::BTreeSet;
 use std::fmt::Display;
 use std::ops::ControlFlow;
-use std::sync::mpsc;
 
+use aoc_utils::grid::directions::{Step, Walker};
 use aoc_utils::grid::{Dir4, Direction, Pos};
+use aoc_utils::par::par_count;
 use aoc_utils::Grid;
 
 fn main() {
@@ -37,47 +38,33 @@ fn main() {
         ControlFlow::Continue(())
     });
 
-    let mut pool = aoc_utils::threadpool();
-
     // Then for each of the tiles we encountered, check if placing an obstacle directly in front of the agent would have
-    // caused a loop to appear.
-    let num_loops = pool.scoped(|scope| {
-        let (send, recv) = mpsc::channel();
-        for &(check_pos, dir) in &all_tiles {
-            let mut map = map.clone();
-
-            // If the tile in front of us isn't already a wall, add a wall there and then begin the simulation.
-            let obs_pos = match dir.checked_add(check_pos, map.size()) {
-                None => continue,
-                Some(p) if map[p].is_wall() || p == start_pos => continue,
-                Some(p) => p,
-            };
-
-            map[obs_pos] = Cell::WALL;
-
-            let send = send.clone();
-            scope.execute(move || {
-                // Run the entire simulation again from the start just to cover our bases. This could be made much more
-                // efficient, but should be good enough for now.
-                let loop_detected = run_simulation(start_pos, map, |cell, _, dir| {
-                    // If, during the course of this simulation, we encounter a cell that we have already visited, while
-                    // also going the same direction we were going before, then we have a loop.
-                    if cell.has_been_visited(dir) {
-                        ControlFlow::Break(())
-                    } else {
-                        ControlFlow::Continue(())
-                    }
-                })
-                .is_some();
-
-                if loop_detected {
-                    send.send(obs_pos).unwrap();
-                }
-            });
-        }
-
-        drop(send); // Now all senders are in the threads, so the channel will hang up when the last thread finishes.
-        recv.iter().collect::<BTreeSet<_>>().len()
+    // caused a loop to appear. If the tile in front of us is already a wall, there's nothing to place. Since the agent
+    // can pass the same tile multiple times, we'll collect the candidates into a set first to avoid checking any twice.
+    let obstacles = all_tiles
+        .iter()
+        .filter_map(|&(check_pos, dir)| match dir.checked_add(check_pos, map.size()) {
+            Some(p) if map[p].is_wall() || p == start_pos => None,
+            p => p,
+        })
+        .collect::<BTreeSet<_>>();
+
+    let num_loops = par_count(&obstacles, |&obs_pos| {
+        let mut map = map.clone();
+        map[obs_pos] = Cell::WALL;
+
+        // Run the entire simulation again from the start just to cover our bases. This could be made much more
+        // efficient, but should be good enough for now.
+        run_simulation(start_pos, map, |cell, _, dir| {
+            // If, during the course of this simulation, we encounter a cell that we have already visited, while also
+            // going the same direction we were going before, then we have a loop.
+            if cell.has_been_visited(dir) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_some()
     });
 
     println!("Number of unique tiles encountered (part 1): {}", num_unique);
@@ -89,12 +76,13 @@ fn main() {
 /// `on_visit` accepts a closure to run once for each cell in the grid. That closure should return a [`ControlFlow`]
 /// dictating whether or not the simulation should continue looping or not. If the loop is stopped by a
 /// [`ControlFlow::Break`], then the whole function will return a `Some(T)` holding the value contained by the `Break`.
-fn run_simulation<T, F>(mut pos: Pos, mut map: Grid<Cell>, mut on_visit: F) -> Option<T>
+fn run_simulation<T, F>(pos: Pos, mut map: Grid<Cell>, mut on_visit: F) -> Option<T>
 where
     F: FnMut(Cell, Pos, Dir4) -> ControlFlow<T, ()>,
 {
-    let mut dir = Dir4::Up;
+    let mut guard = Walker::new(pos, Dir4::Up, map.size());
     loop {
+        let (pos, dir) = guard.state();
         if let ControlFlow::Break(res) = on_visit(map[pos], pos, dir) {
             break Some(res);
         }
@@ -103,10 +91,10 @@ where
 
         // Step forwards and see if we're about to hit a wall or if we've stepped out of bounds or not. When turning
         // right, let the loop restart so `on_visit` can re-run for the new direction.
-        match dir.checked_add(pos, map.size()) {
-            Some(next_pos) if map[next_pos].is_wall() => dir = dir.right(),
-            Some(next_pos) => pos = next_pos,
-            None => break None,
+        match guard.step(|next_pos| map[next_pos].is_wall()) {
+            Step::Blocked(_) => guard.turn_right(),
+            Step::Moved(_) => {},
+            Step::OutOfBounds => break None,
         }
     }
 }
@@ -1,41 +1,31 @@
 use std::str::FromStr;
-use std::sync::mpsc;
+
+use aoc_utils::par::par_map;
+use aoc_utils::search::{self, SearchProblem};
 
 fn main() {
     let input = aoc_utils::puzzle_input();
     let input = input.lines().map(|line| line.parse::<Equation>().unwrap());
 
-    let mut pool = aoc_utils::threadpool();
-    let (tx1, rx1) = mpsc::channel();
-    let (tx2, rx2) = mpsc::channel();
-    pool.scoped(|scope| {
-        for eq in input {
-            let tx1 = tx1.clone();
-            let tx2 = tx2.clone();
-            scope.execute(move || {
-                let solutions = eq.find_solutions();
-                if solutions.len() > 0 {
-                    tx2.send(eq.value).unwrap();
-
-                    // Part 1 didn't have the notion of concatenation, so we only want to count towards its total if
-                    let no_concat = solutions.into_iter().all(|sol| !sol.into_iter().any(|op| op == Op::Concat));
-                    if no_concat {
-                        tx1.send(eq.value).unwrap();
-                    }
-                }
-            });
+    let sums = par_map(input, |eq| {
+        let solutions = eq.find_solutions();
+        if solutions.len() > 0 {
+            // Part 1 didn't have the notion of concatenation, so we only want to count towards its total if
+            let no_concat = solutions.into_iter().all(|sol| !sol.into_iter().any(|op| op == Op::Concat));
+            let part1 = if no_concat { eq.value } else { 0 };
+            (part1, eq.value)
+        } else {
+            (0, 0)
         }
     });
 
-    drop(tx1);
-    drop(tx2);
-    let sum1: usize = rx1.iter().sum();
-    let sum2: usize = rx2.iter().sum();
+    let sum1: usize = sums.iter().map(|&(s1, _)| s1).sum();
+    let sum2: usize = sums.iter().map(|&(_, s2)| s2).sum();
     println!("Sum of solvable equations without concatenation (part 1): {sum1}");
     println!("Sum of solvable equations with concatenation (part 2): {sum2}");
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 enum Op {
     Add,
     Mul,
@@ -64,58 +54,46 @@ impl FromStr for Equation {
     }
 }
 
-impl Equation {
-    pub fn find_solutions(&self) -> Vec<Vec<Op>> {
-        let Equation { value, terms } = self;
-
-        fn recurse(x: usize, r_terms: &[usize], goal: usize, path: Vec<Op>, solutions: &mut Vec<Vec<Op>>) {
-            if r_terms.len() == 0 {
-                if x == goal {
-                    // This path was successful!
-                    solutions.push(path);
-                }
-            } else if x > goal {
-                // If our number ever gets larger than the goal, we can instantly drop the rest of this branch, since
-                // all subsequent additions or multiplications will only make it even larger.
-                return;
-            } else {
-                let mut add_path = duplicate_vec(&path);
-                let mut mul_path = duplicate_vec(&path);
-                let mut cat_path = path;
-                add_path.push(Op::Add);
-                mul_path.push(Op::Mul);
-                cat_path.push(Op::Concat);
-                recurse(x + r_terms[0], &r_terms[1..], goal, add_path, solutions);
-                recurse(x * r_terms[0], &r_terms[1..], goal, mul_path, solutions);
-                recurse(concat(x, r_terms[0]), &r_terms[1..], goal, cat_path, solutions);
-            }
-        }
-
-        if terms.len() == 0 {
-            return vec![];
-        }
-
-        let mut solution_paths = Vec::new();
-        let base_path = Vec::with_capacity(terms.len());
+/// A partially evaluated equation: the value so far, how many terms have been used, and the operator that was used
+/// to apply the last one.
+type EqState = (usize, usize, Option<Op>);
+
+impl SearchProblem for Equation {
+    type State = EqState;
+    type Cost = usize;
+
+    fn successors(&self, &(x, i, _): &EqState) -> impl IntoIterator<Item = EqState> {
+        self.terms.get(i).into_iter().flat_map(move |&t| {
+            [
+                (x + t, i + 1, Some(Op::Add)),
+                (x * t, i + 1, Some(Op::Mul)),
+                (concat(x, t), i + 1, Some(Op::Concat)),
+            ]
+        })
+    }
 
-        recurse(
-            terms[0],
-            terms.get(1..).unwrap_or_default(),
-            *value,
-            base_path,
-            &mut solution_paths,
-        );
+    fn is_goal(&self, &(x, i, _): &EqState) -> bool {
+        i == self.terms.len() && x == self.value
+    }
 
-        solution_paths
+    fn prune(&self, &(x, _, _): &EqState) -> bool {
+        // If our number ever gets larger than the goal, we can instantly drop the rest of this branch, since all
+        // subsequent additions or multiplications will only make it even larger.
+        x > self.value
     }
 }
 
-/// Clones a vector ensuring it has the same capacity as the original.
-#[inline]
-fn duplicate_vec<T: Clone>(v: &Vec<T>) -> Vec<T> {
-    let mut new = Vec::with_capacity(v.capacity());
-    new.clone_from(&v);
-    new
+impl Equation {
+    pub fn find_solutions(&self) -> Vec<Vec<Op>> {
+        let Some(&first) = self.terms.first() else {
+            return vec![];
+        };
+
+        search::dfs_all(self, (first, 1, None))
+            .into_iter()
+            .map(|sol| sol.path.into_iter().filter_map(|(_, _, op)| op).collect())
+            .collect()
+    }
 }
 
 /// Concatenates the base-10 digits of two numbers.
@@ -1,8 +1,7 @@
 use std::collections::HashMap;
-use std::sync::mpsc::{self, Receiver, Sender};
-use std::thread::JoinHandle;
 
 use aoc_utils::grid::Pos as Position;
+use aoc_utils::par::par_map;
 use aoc_utils::Grid;
 
 // cspell:words antinode antinodes
@@ -24,26 +23,10 @@ fn main() {
     .unwrap();
 
     let grid_size = grid.size();
+    let antinodes = par_map(&antennae, |(_code, positions)| scan_antinodes(positions, grid_size));
 
-    let (tx1, rx1) = mpsc::channel(); // Channel for part1 positions
-    let (tx2, rx2) = mpsc::channel(); // Channel for part2 positions
-    let reducer1 = reducer(rx1, grid.clone());
-    let reducer2 = reducer(rx2, grid);
-
-    let mut pool = aoc_utils::threadpool();
-    pool.scoped(|scope| {
-        for (_code, positions) in &antennae {
-            let tx1 = tx1.clone();
-            let tx2 = tx2.clone();
-            scope.execute(move || scan_antinodes(tx1, tx2, positions, grid_size));
-        }
-    });
-
-    drop(tx1);
-    drop(tx2);
-
-    let num_pos1 = reducer1.join().unwrap();
-    let num_pos2 = reducer2.join().unwrap();
+    let num_pos1 = count_unique(antinodes.iter().flat_map(|(part1, _)| part1), grid.clone());
+    let num_pos2 = count_unique(antinodes.iter().flat_map(|(_, part2)| part2), grid);
     println!("Unique locations with an antinode, part 1 rules: {num_pos1}");
     println!("Unique locations with an antinode, part 2 rules: {num_pos2}");
 }
@@ -55,7 +38,9 @@ fn cartesian_product<'a, T>(items: &'a [T]) -> impl Iterator<Item = (&'a T, &'a
         .flat_map(|a| items.iter().filter(|&b| !std::ptr::eq(a, b)).map(move |b| (a, b)))
 }
 
-fn scan_antinodes(tx1: Sender<Position>, tx2: Sender<Position>, positions: &[Position], grid_size: (usize, usize)) {
+/// Finds all the antinodes created by a set of antennae with the same frequency. Returns the antinodes' positions under
+/// part 1 and part 2's rules, respectively.
+fn scan_antinodes(positions: &[Position], grid_size: (usize, usize)) -> (Vec<Position>, Vec<Position>) {
     // Helper function to convert a signed position to an unsigned one, as well as checking for bounds within the
     // original grid.
     let (grid_w, grid_h) = grid_size;
@@ -65,6 +50,9 @@ fn scan_antinodes(tx1: Sender<Position>, tx2: Sender<Position>, positions: &[Pos
         (x < grid_w && y < grid_h).then_some((x, y))
     };
 
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+
     // For every antenna `a`, we want to loop over all the other antennae `b` and find the distance from `a` to `b` (as
     // a vector).
     for (a_pos, b_pos) in cartesian_product(&positions) {
@@ -79,34 +67,33 @@ fn scan_antinodes(tx1: Sender<Position>, tx2: Sender<Position>, positions: &[Pos
         let mut x = bx as isize;
         let mut y = by as isize;
         if let Some(pos) = get_idx(x + dx, y + dy) {
-            tx1.send(pos).unwrap();
+            part1.push(pos);
         }
 
         // Part 2: Start at `b` and keep adding until we leave the map (see `get_idx` helper above).
         while let Some(pos) = get_idx(x, y) {
-            tx2.send(pos).unwrap();
+            part2.push(pos);
             x = x + dx;
             y = y + dy;
         }
     }
-}
 
-/// Spawns a reducer thread that receives positions for the given [`Receiver`] and counts all of the ones which were the
-/// first on that tile.
-fn reducer(receiver: Receiver<Position>, mut grid: Grid<bool>) -> JoinHandle<u32> {
-    std::thread::spawn(move || {
-        let mut unique_positions = 0;
+    (part1, part2)
+}
 
-        for pos in receiver.iter() {
-            let has_antinode = &mut grid[pos];
+/// Counts the number of unique positions in the given list, using the given grid to mark the ones that have been seen.
+fn count_unique<'a>(positions: impl IntoIterator<Item = &'a Position>, mut grid: Grid<bool>) -> u32 {
+    let mut unique_positions = 0;
 
-            if !*has_antinode {
-                unique_positions += 1;
-            }
+    for &pos in positions {
+        let has_antinode = &mut grid[pos];
 
-            *has_antinode = true;
+        if !*has_antinode {
+            unique_positions += 1;
         }
 
-        unique_positions
-    })
+        *has_antinode = true;
+    }
+
+    unique_positions
 }
@@ -1,7 +1,7 @@
 use std::collections::BTreeSet;
-use std::sync::mpsc;
 
 use aoc_utils::grid::Pos as Position;
+use aoc_utils::par::par_map;
 use aoc_utils::Grid;
 
 fn main() {
@@ -22,28 +22,10 @@ fn main() {
     })
     .unwrap();
 
-    let (tx1, rx1) = mpsc::channel();
-    let (tx2, rx2) = mpsc::channel();
-    let mut pool = aoc_utils::threadpool();
+    let results = par_map(&trailheads, |&pos| scan_trailhead(&map, pos));
 
-    pool.scoped(|scope| {
-        for pos in trailheads {
-            let tx1 = tx1.clone();
-            let tx2 = tx2.clone();
-            let map = &map;
-            scope.execute(move || {
-                let (score, rating) = scan_trailhead(map, pos);
-                tx1.send(score).unwrap();
-                tx2.send(rating).unwrap();
-            });
-        }
-    });
-
-    drop(tx1);
-    drop(tx2);
-
-    let score_sum = rx1.iter().fold(0, |acc, cur| acc + cur);
-    let rating_sum = rx2.iter().fold(0, |acc, cur| acc + cur);
+    let score_sum = results.iter().fold(0, |acc, (score, _)| acc + score);
+    let rating_sum = results.iter().fold(0, |acc, (_, rating)| acc + rating);
     println!("Sum of all trailhead scores (part 1): {score_sum}");
     println!("Sum of all trailhead ratings (part 2): {rating_sum}");
 }
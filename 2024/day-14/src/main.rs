@@ -9,6 +9,7 @@ use std::time::Duration;
 
 use aoc_utils::Grid;
 use aoc_utils::clap::{self, Parser};
+use aoc_utils::vec::Vec2;
 use crossterm::QueueableCommand;
 use crossterm::cursor::{self, DisableBlinking, EnableBlinking, Hide as HideCursor, MoveTo, Show as ShowCursor};
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
@@ -18,7 +19,7 @@ use crossterm::style::{Color, Print, SetBackgroundColor, SetForegroundColor};
 use crossterm::terminal::{self, Clear, ClearType, DisableLineWrap, EnableLineWrap};
 
 use self::charsets::Charset;
-use self::robot::{Robot, Vec2};
+use self::robot::Robot;
 
 pub const MAP_W: usize = 101;
 pub const MAP_H: usize = 103;
@@ -148,11 +149,11 @@ impl App {
 
         self.timestamp += n_steps;
 
-        let limits = self.counts.size().try_into().unwrap();
+        let limits = Vec2::from_pos(self.counts.size()).unwrap();
         for robot in &mut self.robots {
             self.counts[robot.pos] -= 1;
             robot.pos += robot.vel * n_steps;
-            robot.pos.wrapping_clamp(&limits);
+            robot.pos = robot.pos.rem_euclid(limits);
             self.counts[robot.pos] += 1;
         }
     }
@@ -324,7 +325,7 @@ impl App {
     }
 }
 
-fn quadrant(pos: &Vec2, width: usize, height: usize) -> Option<u8> {
+fn quadrant(pos: &Vec2<i32>, width: usize, height: usize) -> Option<u8> {
     let &Vec2 { x, y } = pos;
     let x_mid = (width / 2) as i32;
     let y_mid = (height / 2) as i32;
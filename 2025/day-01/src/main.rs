@@ -1,3 +1,5 @@
+use aoc_utils::grid::turns;
+
 fn main() {
     let input = aoc_utils::puzzle_input();
     let lines = input.lines();
@@ -93,11 +95,5 @@ fn main() {
 }
 
 fn parse_rotation(line: &str) -> isize {
-    assert!(line.len() > 0 && line.is_char_boundary(1), "invalid puzzle input");
-    let amt = line[1..].parse::<isize>().expect("invalid puzzle input");
-    match line.as_bytes()[0] {
-        b'L' => -amt,
-        b'R' => amt,
-        _ => panic!("invalid puzzle input"),
-    }
+    turns::parse_rotation(line).expect("invalid puzzle input")
 }
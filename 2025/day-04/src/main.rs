@@ -1,50 +1,32 @@
 use std::fmt::Debug;
 
 use aoc_utils::Grid;
+use aoc_utils::grid::{Automaton, Neighbourhood};
 
 fn main() {
     let input = aoc_utils::puzzle_input();
-    let mut map = Grid::from_lines_map(input.lines(), |c, _| match c {
+    let map = Grid::from_lines_map(input.lines(), |c, _| match c {
         '@' => Cell::Paper,
         '.' => Cell::Empty,
         _ => panic!("invalid puzzle input: unknown char '{c}'"),
     })
     .unwrap();
 
-    let mut count1 = 0usize;
-    find_reachable_papers(&mut map, |_| count1 += 1);
-
-    let mut count2 = 0usize;
-    loop {
-        let mut num = 0usize;
-        find_reachable_papers(&mut map, |cell| {
-            *cell = Cell::Empty;
-            num += 1;
-        });
-
-        if num == 0 {
-            break;
-        }
+    // A roll of paper can be reached (and removed) when there are fewer than four other rolls around it.
+    let mut automaton = Automaton::new(map, Neighbourhood::Around, |&cell, around| match cell {
+        Cell::Paper if around.filter(|&&c| c == Cell::Paper).count() < 4 => Cell::Empty,
+        cell => cell,
+    })
+    .track_changes(true);
 
-        count2 += num;
-    }
+    let count1 = automaton.step();
+    automaton.run_until_stable();
+    let count2 = automaton.changes().iter().sum::<usize>();
 
     println!("Number of initially reachable rolls (part 1): {count1}");
     println!("Total Number of paper rolls removed (part 2): {count2}");
 }
 
-fn find_reachable_papers(map: &mut Grid<Cell>, mut cb: impl FnMut(&mut Cell)) {
-    for pos in map.positions() {
-        if map[pos] == Cell::Paper {
-            let neighbours = map.neighbours(pos).expect("pos should be in bounds");
-            let num_papers = neighbours.iter_around().filter(|&p| map[p] == Cell::Paper).count();
-            if num_papers < 4 {
-                cb(&mut map[pos]);
-            }
-        }
-    }
-}
-
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Cell {
     Paper,
@@ -1,12 +1,9 @@
-mod disjoint;
-
 use std::cmp::Reverse;
-use std::fmt::Display;
-use std::str::FromStr;
 
 use aoc_utils::clap;
-
-use crate::disjoint::DisjointSet;
+use aoc_utils::disjoint::DisjointSet;
+use aoc_utils::kdtree::KdTree;
+use aoc_utils::vec::Vec3;
 
 // This puzzle requires additional input not present in the input text file.
 //
@@ -42,14 +39,14 @@ struct Args {
 fn main() {
     // - We start by parsing all the junctions into a single list right from the get-go. We'll use their indices to
     //   refer to them throughout the rest of the solution.
-    // - We immediately compute all possible pairs of indices; there aren't really that many. This list excludes
-    //   self-references and duplicates.
+    // - We put them into a k-d tree, which can lazily hand us pairs of junctions in order of their distance. That way,
+    //   we only look at as many pairs as we need, instead of computing and sorting all of them up front.
     // - We use a disjoint set union to keep track of which junctions are connected to one another.
     let junctions = aoc_utils::puzzle_input()
         .lines()
         .map(|line| line.parse().unwrap())
         .collect::<Vec<JunctionBox>>();
-    let pairs = compute_sorted_pairs(&junctions[..]);
+    let tree = KdTree::new(junctions.iter().copied());
     let mut circuits = DisjointSet::with_len(junctions.len());
 
     // Puzzle parameters depend slightly based on the size of the input:
@@ -57,11 +54,14 @@ fn main() {
     let closest_n = closest_n.unwrap_or(if junctions.len() < 50 { 10 } else { junctions.len() });
     let largest_m = largest_m.unwrap_or(3);
 
-    if closest_n > pairs.len() {
+    // Summation identity: `sum_(i=0)^(n-1)(sum_(j=0)^(i-1)(1)) = (1/2)(n-1)n` (WolframAlpha)
+    // (imagine a bottom-left triangular adjacency matrix).
+    let num_pairs = (junctions.len() - 1) * junctions.len() / 2;
+    if closest_n > num_pairs {
         panic!(
             "Invalid puzzle parameters: attempted to find closest N={} pairs, but only {} pairs exist",
             closest_n,
-            pairs.len(),
+            num_pairs,
         );
     }
 
@@ -69,7 +69,7 @@ fn main() {
     let mut largest_product = None;
     let mut final_x_product = None;
 
-    for (i, j) in pairs {
+    for (i, j, dist) in tree.closest_pairs() {
         // Used for verbose printing:
         let p = pairs_connected + 1;
 
@@ -78,7 +78,6 @@ fn main() {
             let jj = &junctions[j];
             let ci = circuits.find_root(i);
             let cj = circuits.find_root(j);
-            let dist = ji.dist_sq(&jj);
             println!(
                 "Closest pair #{p}: {ji:>17} (#{i:4}, circuit #{ci:4}) and {jj:<17} (#{j:4}, circuit #{cj:4}), sq. dist = {dist}"
             );
@@ -126,7 +125,7 @@ fn main() {
                 println!("\nPair #{p} was last needed to create one circuit: {ji} and {jj} (#{i:4} and #{j:4})\n");
             }
 
-            final_x_product = Some((ji.x as u64) * (jj.x as u64));
+            final_x_product = Some(ji.x * jj.x);
             break;
         }
     }
@@ -140,76 +139,5 @@ fn main() {
     println!("Product of final pair of junction boxes' X-coordinates (part 2): {final_x_product}");
 }
 
-#[derive(Debug, Clone, Copy)]
-struct JunctionBox {
-    x: u32,
-    y: u32,
-    z: u32,
-}
-
-impl JunctionBox {
-    /// Returns the squared Euclidean distance between this junction box and another.
-    pub fn dist_sq(&self, other: &JunctionBox) -> u64 {
-        let dx = self.x.abs_diff(other.x) as u64;
-        let dy = self.y.abs_diff(other.y) as u64;
-        let dz = self.z.abs_diff(other.z) as u64;
-        (dx * dx) + (dy * dy) + (dz * dz)
-    }
-}
-
-impl FromStr for JunctionBox {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut bits = s.split(',');
-        let mut xyz = [0u32; 3];
-
-        for i in 0..3 {
-            xyz[i] = bits
-                .next()
-                .ok_or("junction box has less than 3 fields")?
-                .parse::<u32>()
-                .map_err(|_| "junction box contains invalid numbers")?;
-        }
-
-        if bits.count() != 0 {
-            return Err("junction box has more than 3 fields");
-        }
-
-        let [x, y, z] = xyz;
-        Ok(Self { x, y, z })
-    }
-}
-
-impl Display for JunctionBox {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use std::fmt::Alignment;
-
-        let JunctionBox { x, y, z } = self;
-        let str = format!("{x},{y},{z}");
-
-        match (f.width(), f.align()) {
-            (Some(w), Some(Alignment::Left)) => write!(f, "{str:<w$}"),
-            (Some(w), Some(Alignment::Right)) => write!(f, "{str:>w$}"),
-            (Some(w), Some(Alignment::Center)) => write!(f, "{str:^w$}"),
-            (Some(w), None) => write!(f, "{str:w$}"),
-            (None, _) => write!(f, "{str}"),
-        }
-    }
-}
-
-/// Computes a list of all possible `(i, j)` pairs without any self-references or repeats.
-fn compute_sorted_pairs(junctions: &[JunctionBox]) -> Vec<(usize, usize)> {
-    // Summation identity: `sum_(i=0)^(n-1)(sum_(j=0)^(i-1)(1)) = (1/2)(n-1)n` (WolframAlpha)
-    // (imagine a bottom-left triangular adjacency matrix).
-    let num_pairs = (junctions.len() - 1) * junctions.len() / 2;
-    let mut pairs = Vec::with_capacity(num_pairs);
-    for i in 0..junctions.len() {
-        for j in 0..i {
-            pairs.push((i, j));
-        }
-    }
-
-    pairs.sort_unstable_by_key(|&(i, j)| junctions[i].dist_sq(&junctions[j]));
-    pairs
-}
+/// Junction boxes are just points in 3D space.
+type JunctionBox = Vec3<u64>;
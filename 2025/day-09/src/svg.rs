@@ -4,7 +4,7 @@
 
 use std::fmt::Write;
 
-use crate::shapes::{Point, Polygon};
+use aoc_utils::geom::{Point, Polygon};
 
 const SVG_DOCTYPE: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>
 <!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd">
@@ -54,10 +54,10 @@ pub fn render_polygon(polygon: &Polygon) -> String {
     write!(&mut result, r##"<svg xmlns="http://www.w3.org/2000/svg" version="1.1" viewBox=""##).unwrap();
 
     // viewBox:
-    let max_x = max_x.unwrap_or(100) as i64;
-    let max_y = max_y.unwrap_or(100) as i64;
-    let mut min_x = min_x.unwrap_or(0) as i64;
-    let mut min_y = min_y.unwrap_or(0) as i64;
+    let max_x = max_x.unwrap_or(100);
+    let max_y = max_y.unwrap_or(100);
+    let mut min_x = min_x.unwrap_or(0);
+    let mut min_y = min_y.unwrap_or(0);
     let mut w = max_x - min_x;
     let mut h = max_y - min_y;
 
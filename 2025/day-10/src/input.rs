@@ -2,7 +2,7 @@ use std::fmt::Debug;
 use std::num::IntErrorKind;
 use std::str::FromStr;
 
-use crate::debug::BitDebugExt;
+use aoc_utils::bits::BitDebugExt;
 
 // cspell:words joltage joltages
 
@@ -1,11 +1,12 @@
-mod debug;
 mod input;
 
 use std::collections::HashMap;
 
+use aoc_utils::bits::IterBitExt;
+use aoc_utils::math::ilp::minimize_int;
 use aoc_utils::vprintln;
 
-use self::input::{Bitfield, Joltage, Machine};
+use self::input::{Bitfield, Machine};
 
 // cspell:words joltage joltages
 
@@ -33,14 +34,11 @@ fn main() {
         //   `(A ^ B ^ C ^ D) ^ B = (A ^ (B ^ B) ^ C ^ D) = (A ^ 0 ^ C ^ D) = (A ^ C ^ D)`.
         // - That means that, for any given parity, each button will be pressed exactly zero or one time.
         //
-        // We can apply the same logic to help us solve part 2: instead of toggling the machine's lights, each button
-        // press toggles the *parity* of the joltage counters. Over the course of part 2, we'll need to recursively
-        // check many, many possible configurations of the lights/parity; so the most efficient course of action here is
-        // to precompute all the possible combinations.
+        // So, we can precompute which combinations of buttons yield each possible light pattern.
         let parity_combos = compute_parity_map(&machine);
 
         let light_presses = configure_lights(&machine, &parity_combos);
-        let joltage_presses = configure_joltages(&machine, &parity_combos);
+        let joltage_presses = configure_joltages(&machine);
 
         vprintln!(1, "\tPresses for lights: {light_presses}");
         vprintln!(1, "\tPresses for joltage: {joltage_presses}\n");
@@ -54,21 +52,16 @@ fn main() {
 }
 
 /// Computes **all** possible button combinations on this machine that would yield **any** possible parity on its
-/// lights/joltage counters.
+/// lights.
 ///
 /// Since each button is always pressed exactly 0 or 1 times, the combinations are returned as bitmasks which describe
 /// indices in the machine's `buttons` array that should be pressed to achieve the desired parity.
 fn compute_parity_map(machine: &Machine) -> HashMap<Bitfield, Vec<usize>> {
-    // In theory, we could have gotten away with not computing all possible combinations for all possible parities. We
-    // *could* have populated a map lazily while doing the recursion in `configure_joltages`; however, that introduces
-    // lifetime issues when trying to do recursion. Each step would try to push a its newly discovered button
-    // combinations into the map, but the upper level would still be holding onto its list of combinations; trying to
-    // push into the hashmap might cause a reallocation and move the vector out from under the upper level. Since there
-    // are at most 2^10 = 1024 possible parity configurations for any given machine, though, it's really not so bad to
-    // compute them up front.
+    // There are at most 2^10 = 1024 possible parity configurations for any given machine, so it's really not so bad to
+    // compute them all up front.
     let mut parity_combos = HashMap::new();
 
-    // What are all the possible *parity* values this machine could have on its lights/joltage counters?
+    // What are all the possible *parity* values this machine could have on its lights?
     let max_parity_mask = Bitfield::MAX >> (Bitfield::BITS - machine.size() as u32); // 1 << N, but avoids overflow
     let max_button_mask = usize::MAX >> (usize::BITS - machine.buttons.len() as u32);
 
@@ -104,134 +97,20 @@ fn configure_lights(machine: &Machine, parity_combos: &HashMap<Bitfield, Vec<usi
 }
 
 /// Determines the optimal set of buttons to press to configure a machine's joltage counters.
-fn configure_joltages(machine: &Machine, parity_combos: &HashMap<Bitfield, Vec<usize>>) -> u64 {
-    // To keep things fast, we'll use cache the optimal solutions for each of the possible joltage values, since the end
-    // of the recursion tree will likely have a lot of repetition. Also, it's a good idea to cache whether or not the
-    // even is a solution for that particular set of joltages, so we'll use an Option. We can initialize the solution
-    // for `[0, 0, 0, ..., 0]` joltages now, since we already know it would take zero presses to reach joltages of all
-    // zeroes.
-    let mut joltage_solutions = HashMap::<Box<[Joltage]>, Option<u64>>::new();
-    joltage_solutions.insert(vec![0; machine.joltages.len()].into(), Some(0));
-
-    fn recurse(
-        buttons: &[Bitfield],
-        joltages: Box<[Joltage]>,
-        parity_combos: &HashMap<Bitfield, Vec<usize>>,
-        joltage_solutions: &mut HashMap<Box<[Joltage]>, Option<u64>>,
-        depth: usize,
-    ) -> Option<u64> {
-        // First, a preliminary check: do we already know how many button presses this particular set of joltages takes?
-        // Note that this also covers the case of all zeroes.
-        if let Some(answer) = joltage_solutions.get(&joltages).copied() {
-            return answer;
-        }
-
-        // Okay, now: what parity does this branch of the recursion tree need to figure out the presses for? Which
-        // button combinations will yield that parity, if any?
-        let curr_parity = parity(&joltages);
-        let curr_combos = parity_combos[&curr_parity].as_slice(); // Could be empty!
-
-        // Okay, for each of those button combinations, which one yields the best solution? This will be `None` if there
-        // are no possible button combos that lead to the desired parity.
-        let min_presses = curr_combos
-            .into_iter()
-            .filter_map(|&button_mask| -> Option<u64> {
-                // Apply this set of buttons to our joltages, what do we get? If applying the buttons goes below 0 jolts
-                // on any counter, it's not a valid solution; short-circuit.
-                let mut next_joltages = joltages.clone();
-                for &button in buttons.iter().bit_filter(button_mask) {
-                    for joltage in next_joltages.iter_mut().bit_filter(button) {
-                        *joltage = joltage.checked_sub(1)?;
-                    }
-                }
-
-                // At this point, if we can figure out the optimal way to reach `next_joltages`, we would simply have to
-                // perform `curr_presses` to get the final result we want. Since `curr_presses` were determined simply
-                // based on *parity*, applying them should leave all the joltages as even numbers. That is, they're all
-                // divisible by two! So, we can divide `next_joltages` by two, figure out *those* joltages' optimal
-                // solution, then multiply the answer we get by two.
-                for joltage in next_joltages.iter_mut() {
-                    *joltage >>= 1;
-                }
-
-                let next_presses = recurse(buttons, next_joltages, parity_combos, joltage_solutions, depth + 1)?;
-                let curr_presses = button_mask.count_ones() as u64;
-                Some(curr_presses + (next_presses << 1))
-            })
-            .min();
-
-        // If we didn't already know the answer, store it before returning.
-        joltage_solutions.insert(joltages, min_presses);
-        min_presses
-    }
-
-    // We're going to have the clone the joltage slice later anyways to be able to put it into our hashmap. Doing it now
-    // and lets the inner function accept the owned slice directly, which lets us avoid having to clone it a second time
-    // during recursion, all while letting the outer function keep a consistent interface with `configure_lights`.
-    let buttons = &machine.buttons[..];
-    let joltages = machine.joltages.clone();
-    recurse(buttons, joltages, parity_combos, &mut joltage_solutions, 0).expect(AT_LEAST_ONE)
-}
-
-/// Checks if the `i`'th bit is set in a bit-mask.
-macro_rules! bit_set {
-    ($x:expr, $i:expr) => {
-        (($x >> $i) & 1) == 1
-    };
-}
-
-pub(crate) use bit_set;
-
-/// Determines the parity of a series of [Joltage] counters.
-fn parity(counters: &[Joltage]) -> Bitfield {
-    let mut bits: Bitfield = 0;
-    for i in 0..counters.len() {
-        let b = counters[i] & 1; // 1 for odd, 0 for even
-        bits |= b << i;
-    }
-    bits
-}
-
-/// An extension trait that adds the [`bit_filter`][IterBitExt::bit_filter] method to iterators.
-trait IterBitExt: Iterator + Sized {
-    /// Filters items in this iterator based on the given bitmask and their indices.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// let x = [1, 2, 3, 4, 5, 6, 7, 8];
-    ///
-    /// // Select items 0, 1, 4, and 7:
-    /// let y = x.into_iter().bit_filter(0b10010011).collect::<Vec<_>>();
-    ///
-    /// assert_eq!(&y, &[1, 2, 5, 8]);
-    /// ```
-    fn bit_filter(self, mask: usize) -> BitFilter<Self> {
-        BitFilter { iter: self.enumerate(), mask }
-    }
-}
-
-/// `IterBitExt` is automatically implemented for all iterators.
-impl<I: Iterator + Sized> IterBitExt for I {}
-
-/// An iterator that filters its contents based on a bitmask. See [`IterBitExt::bit_filter`] for details.
-struct BitFilter<I: Iterator> {
-    iter: std::iter::Enumerate<I>,
-    mask: usize,
-}
-
-impl<I: Iterator> Iterator for BitFilter<I> {
-    type Item = I::Item;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let (i, item) = self.iter.next()?;
-            // Handle possible left-shift overflow for iterators >=64 bits long:
-            if i >= (usize::BITS as usize) {
-                return None;
-            } else if bit_set!(self.mask, i) {
-                return Some(item);
-            }
-        }
-    }
+fn configure_joltages(machine: &Machine) -> u64 {
+    // This one is an integer linear program. Each button `j` gets pressed some number of times `x[j]`; for each counter
+    // `i`, the presses of all the buttons wired to that counter need to add up to its joltage. Minimize the total:
+    //
+    //     minimize    x[0] + x[1] + ... + x[m]
+    //     subject to  (sum of x[j] for each button j that bumps counter i) = joltage[i], for each counter i
+    //                 x[j] >= 0, integer
+    let cost = vec![1i64; machine.buttons.len()];
+    let counters = (0..machine.joltages.len())
+        .map(|i| machine.buttons.iter().map(|&button| ((button >> i) & 1) as i64).collect())
+        .collect::<Vec<_>>();
+    let targets = machine.joltages.iter().map(|&j| j as i64).collect::<Vec<_>>();
+
+    let outcome = minimize_int(&cost, &counters, &targets);
+    vprintln!(2, "\tJoltage button presses: {:?}", outcome.solution().expect(AT_LEAST_ONE));
+    *outcome.value().expect(AT_LEAST_ONE) as u64
 }
@@ -0,0 +1,163 @@
+//! Pretty-printing for bitfields.
+
+use std::fmt::{self, Debug};
+
+use super::{BitMask, DynBitSet};
+
+/// An extension trait for printing bitfields in a more readable way than `{:b}`.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bits::BitDebugExt;
+/// let lights = 0b0110usize;
+///
+/// // Printed starting from the lowest bit, the same way that a puzzle would list lights from left to right:
+/// assert_eq!(format!("{:?}", lights.dbg_bitfield(4)), "0110");
+/// assert_eq!(format!("{:?}", lights.dbg_bitfield(4).chars('.', '#')), ".##.");
+/// assert_eq!(format!("{:?}", lights.dbg_bit_indices()), "1,2");
+/// ```
+pub trait BitDebugExt {
+    /// Creates a wrapper struct that prints the first `width` bits of this bitfield, lowest bit first.
+    ///
+    /// In alternate mode (`{:#?}`), the regular binary representation (highest bit first) is printed afterwards.
+    fn dbg_bitfield(&self, width: usize) -> BitfieldPrinter;
+
+    /// Creates a wrapper struct that prints the indices of the set bits in this bitfield, separated by commas.
+    fn dbg_bit_indices(&self) -> BitIndicesPrinter;
+}
+
+impl<M: BitMask> BitDebugExt for M {
+    fn dbg_bitfield(&self, width: usize) -> BitfieldPrinter {
+        BitfieldPrinter {
+            bits: to_dyn(self),
+            width,
+            chars: ['0', '1'],
+            color: None,
+        }
+    }
+
+    fn dbg_bit_indices(&self) -> BitIndicesPrinter {
+        BitIndicesPrinter { bits: to_dyn(self), sep: "," }
+    }
+}
+
+fn to_dyn(mask: &impl BitMask) -> DynBitSet {
+    (0..mask.bit_end()).filter(|&i| mask.bit(i)).collect()
+}
+
+/// Prints a bitfield as a string of ones and zeroes. See [`BitDebugExt::dbg_bitfield`].
+pub struct BitfieldPrinter {
+    bits: DynBitSet,
+    width: usize,
+    chars: [char; 2],
+    color: Option<Color>,
+}
+
+/// Prints a bitfield as a list of its set bits' indices. See [`BitDebugExt::dbg_bit_indices`].
+pub struct BitIndicesPrinter {
+    bits: DynBitSet,
+    sep: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Cyan,
+    Green,
+    White,
+}
+
+#[rustfmt::skip]
+impl BitfieldPrinter {
+    /// Sets the characters to use for printing zeroes and ones respectively.
+    pub fn chars(self, c0: char, c1: char) -> Self {
+        Self { chars: [c0, c1], ..self }
+    }
+
+    /// This printer will print ones in red.
+    pub fn red(self) -> Self {
+        Self { color: Some(Color::Red), ..self }
+    }
+
+    /// This printer will print ones in cyan.
+    pub fn cyan(self) -> Self {
+        Self { color: Some(Color::Cyan), ..self }
+    }
+
+    /// This printer will print ones in green.
+    pub fn green(self) -> Self {
+        Self { color: Some(Color::Green), ..self }
+    }
+
+    /// This printer will print ones in white.
+    pub fn white(self) -> Self {
+        Self { color: Some(Color::White), ..self }
+    }
+}
+
+impl BitIndicesPrinter {
+    /// Updates the separator this printer uses.
+    pub fn sep(self, sep: &'static str) -> Self {
+        Self { sep, ..self }
+    }
+}
+
+mod ansi {
+    pub const RED: &str = "\x1b[38;5;9m";
+    pub const CYAN: &str = "\x1b[38;5;14m";
+    pub const GREEN: &str = "\x1b[38;5;10m";
+    pub const WHITE: &str = "\x1b[38;5;15m"; // Technically "bright" white
+    pub const BLACK: &str = "\x1b[38;5;238m"; // Technically dark gray
+    pub const RESET: &str = "\x1b[0m";
+}
+
+impl Debug for BitfieldPrinter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [char0, char1] = self.chars;
+        let [ansi0, ansi1] = match self.color {
+            None => ["", ""],
+            Some(Color::Red) => [ansi::BLACK, ansi::RED],
+            Some(Color::Cyan) => [ansi::BLACK, ansi::CYAN],
+            Some(Color::Green) => [ansi::BLACK, ansi::GREEN],
+            Some(Color::White) => [ansi::BLACK, ansi::WHITE],
+        };
+
+        for i in 0..self.width {
+            if self.bits.contains(i) {
+                write!(f, "{ansi1}{char1}")?;
+            } else {
+                write!(f, "{ansi0}{char0}")?;
+            }
+        }
+
+        if self.color.is_some() {
+            write!(f, "{}", ansi::RESET)?;
+        }
+
+        if f.alternate() {
+            // Like `{:0width$b}`: at least `width` digits, but more if there are set bits past that.
+            write!(f, "/{}", ansi::BLACK)?;
+            for i in (0..self.width.max(self.bits.bit_end())).rev() {
+                write!(f, "{}", if self.bits.contains(i) { '1' } else { '0' })?;
+            }
+            write!(f, "{}", ansi::RESET)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for BitIndicesPrinter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for i in self.bits.iter() {
+            match first {
+                true => first = false,
+                false => write!(f, "{}", self.sep)?,
+            }
+            write!(f, "{i}")?;
+        }
+        Ok(())
+    }
+}
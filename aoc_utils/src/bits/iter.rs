@@ -0,0 +1,145 @@
+//! Iterators for working with bitfields.
+
+use super::{BitMask, Word};
+
+/// An extension trait that adds the [`bit_filter`][IterBitExt::bit_filter] method to iterators.
+pub trait IterBitExt: Iterator + Sized {
+    /// Filters items in this iterator based on the given bitmask and their indices.
+    ///
+    /// The `i`'th item is kept only if the `i`'th bit of `mask` is set. The iterator stops as soon as it passes the
+    /// highest set bit in the mask, so this is safe to use on infinite iterators.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use aoc_utils::bits::IterBitExt;
+    /// let x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// // Select items 0, 1, 4, and 7:
+    /// let y = x.into_iter().bit_filter(0b10010011usize).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&y, &[1, 2, 5, 8]);
+    /// ```
+    fn bit_filter<M: BitMask>(self, mask: M) -> BitFilter<Self, M> {
+        let end = mask.bit_end();
+        BitFilter { iter: self.enumerate(), mask, end }
+    }
+}
+
+/// `IterBitExt` is automatically implemented for all iterators.
+impl<I: Iterator + Sized> IterBitExt for I {}
+
+/// An iterator that filters its contents based on a bitmask. See [`IterBitExt::bit_filter`] for details.
+#[derive(Debug, Clone)]
+pub struct BitFilter<I: Iterator, M> {
+    iter: std::iter::Enumerate<I>,
+    mask: M,
+    end: usize,
+}
+
+impl<I: Iterator, M: BitMask> Iterator for BitFilter<I, M> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (i, item) = self.iter.next()?;
+            if i >= self.end {
+                return None;
+            } else if self.mask.bit(i) {
+                return Some(item);
+            }
+        }
+    }
+}
+
+/// Returns an iterator over every submask of `mask`: every value whose set bits are all also set in `mask`.
+///
+/// Submasks are yielded in descending order, starting with `mask` itself and ending with zero. A mask with `n` bits set
+/// has `2ⁿ` submasks.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bits::submasks;
+/// let subs = submasks(0b1010u8).collect::<Vec<_>>();
+/// assert_eq!(subs, [0b1010, 0b1000, 0b0010, 0b0000]);
+/// ```
+pub fn submasks<W: Word>(mask: W) -> Submasks<W> {
+    Submasks { mask, next: Some(mask) }
+}
+
+/// An iterator over the submasks of a bitmask. See [`submasks`] for details.
+#[derive(Debug, Clone)]
+pub struct Submasks<W> {
+    mask: W,
+    next: Option<W>,
+}
+
+impl<W: Word> Iterator for Submasks<W> {
+    type Item = W;
+
+    fn next(&mut self) -> Option<W> {
+        let curr = self.next?;
+        self.next = (curr != W::ZERO).then(|| curr.wrapping_sub(W::ONE) & self.mask);
+        Some(curr)
+    }
+}
+
+/// Returns an iterator over every `n`-bit value with exactly `k` bits set, in ascending order.
+///
+/// This is a quick way to iterate over all the ways to choose `k` items out of `n` (when `n` is small enough to fit
+/// into a single [`Word`]); combine it with [`bit_filter`][super::IterBitExt::bit_filter] to get the items themselves.
+/// It uses Gosper's hack to step from one value to the next.
+///
+/// # Panics
+///
+/// This function panics if `n` is larger than the number of bits in `W`.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bits::combinations;
+/// let combos = combinations::<u8>(4, 2).collect::<Vec<_>>();
+/// assert_eq!(combos, [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]);
+/// ```
+pub fn combinations<W: Word>(n: u32, k: u32) -> Combinations<W> {
+    assert!(n <= W::BITS, "cannot choose from more than {} bits", W::BITS);
+    let first = match k {
+        _ if k > n => None,
+        0 => Some(W::ZERO),
+        _ => Some(!W::ZERO >> (W::BITS - k)), // The lowest `k` bits
+    };
+
+    Combinations { n, next: first }
+}
+
+/// An iterator over all the `n`-bit values with exactly `k` bits set. See [`combinations`] for details.
+#[derive(Debug, Clone)]
+pub struct Combinations<W> {
+    n: u32,
+    next: Option<W>,
+}
+
+impl<W: Word> Iterator for Combinations<W> {
+    type Item = W;
+
+    fn next(&mut self) -> Option<W> {
+        let x = self.next?;
+        self.next = if x == W::ZERO {
+            // Zero is the only combination with no bits set.
+            None
+        } else {
+            // Move the lowest block of ones up by one: the top one moves up into the zero above the block, and the
+            // rest of the block drops back down to the bottom.
+            let c = x & x.wrapping_neg(); // The lowest set bit
+            let r = x.wrapping_add(c); // The block is carried into the next zero
+            if r == W::ZERO {
+                None // The carry fell off the top of the word, so `x` was the last one
+            } else {
+                let next = (((r ^ x) >> 2) >> c.trailing_zeros()) | r;
+                (next.leading_zeros() >= W::BITS - self.n).then_some(next)
+            }
+        };
+        Some(x)
+    }
+}
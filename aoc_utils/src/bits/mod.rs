@@ -0,0 +1,671 @@
+//! Bitsets, and helpers for treating plain integers as bitfields.
+//!
+//! Puzzles about switches, lights, valves, and keys often have few enough of them to fit each in a single bit of an
+//! integer. That makes toggling, combining, and comparing whole configurations at once very cheap, and means that every
+//! possible configuration can be enumerated just by counting.
+//!
+//! - Plain unsigned integers can be used as bitfields directly; the [`Word`] trait lets the functions in this module be
+//!   generic over them.
+//! - [`BitSet`] is a fixed-size set of small integers for when one integer isn't quite big enough, and [`DynBitSet`] is
+//!   one that grows as needed.
+//! - [`BitMask`] covers all of the above. It is used to [filter iterators][IterBitExt::bit_filter] and to [print
+//!   bitfields][BitDebugExt] for debugging.
+
+pub mod debug;
+pub mod iter;
+
+use std::fmt::{self, Binary, Debug};
+use std::hash::Hash;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr, Sub, SubAssign};
+
+pub use self::debug::{BitDebugExt, BitIndicesPrinter, BitfieldPrinter};
+pub use self::iter::{BitFilter, Combinations, IterBitExt, Submasks, combinations, submasks};
+
+/// Checks if the `i`'th bit is set in a bit-mask.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bit_set;
+/// assert!(bit_set!(0b0100u8, 2));
+/// assert!(!bit_set!(0b0100u8, 1));
+/// ```
+#[macro_export]
+macro_rules! bit_set {
+    ($x:expr, $i:expr) => {
+        (($x >> $i) & 1) == 1
+    };
+}
+
+/// A primitive unsigned integer type, for use as a bitfield.
+///
+/// This trait is implemented for all of Rust's unsigned integer primitives.
+pub trait Word:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Binary
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    /// A word with no bits set.
+    const ZERO: Self;
+
+    /// A word with only the lowest bit set.
+    const ONE: Self;
+
+    /// The number of bits in this type.
+    const BITS: u32;
+
+    /// Returns the number of ones in the binary representation of this word.
+    fn count_ones(self) -> u32;
+
+    /// Returns the number of trailing zeros in the binary representation of this word.
+    fn trailing_zeros(self) -> u32;
+
+    /// Returns the number of leading zeros in the binary representation of this word.
+    fn leading_zeros(self) -> u32;
+
+    /// Wrapping (modular) addition.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) subtraction.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Wrapping (modular) negation; i.e., the two's complement of this word.
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! impl_word {
+    ($($t:ty),*) => {
+        $(impl Word for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const BITS: u32 = <$t>::BITS;
+
+            fn count_ones(self) -> u32 {
+                <$t>::count_ones(self)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                <$t>::trailing_zeros(self)
+            }
+
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            fn wrapping_neg(self) -> Self {
+                <$t>::wrapping_neg(self)
+            }
+        }
+
+        impl BitMask for $t {
+            fn bit(&self, i: usize) -> bool {
+                i < (<$t>::BITS as usize) && bit_set!(*self, i)
+            }
+
+            fn bit_end(&self) -> usize {
+                (<$t>::BITS - self.leading_zeros()) as usize
+            }
+        })*
+    };
+}
+
+impl_word!(u8, u16, u32, u64, u128, usize);
+
+/// Anything which can be treated as a list of bits: [`Word`]s, [`BitSet`]s and [`DynBitSet`]s.
+pub trait BitMask {
+    /// Checks whether the `i`'th bit is set. Bits past the end of the mask are never set.
+    fn bit(&self, i: usize) -> bool;
+
+    /// Gets the index one past the highest set bit (or zero if no bits are set).
+    fn bit_end(&self) -> usize;
+}
+
+impl<M: BitMask + ?Sized> BitMask for &M {
+    fn bit(&self, i: usize) -> bool {
+        (**self).bit(i)
+    }
+
+    fn bit_end(&self) -> usize {
+        (**self).bit_end()
+    }
+}
+
+/// Gets the index of the word and the mask for the bit within that word for bit `i`.
+const fn locate(i: usize) -> (usize, u64) {
+    (i / 64, 1 << (i % 64))
+}
+
+/// Finds one past the highest set bit in a list of words.
+fn words_bit_end(words: &[u64]) -> usize {
+    match words.iter().rposition(|&w| w != 0) {
+        Some(i) => i * 64 + (64 - words[i].leading_zeros() as usize),
+        None => 0,
+    }
+}
+
+/// An iterator over the indices of the set bits in a [`BitSet`] or [`DynBitSet`], in ascending order.
+#[derive(Debug, Clone)]
+pub struct Ones<'a> {
+    words: &'a [u64],
+    /// The index of the word `curr` came from.
+    base: usize,
+    /// The bits of the current word that haven't been yielded yet.
+    curr: u64,
+}
+
+impl<'a> Ones<'a> {
+    fn new(words: &'a [u64]) -> Self {
+        let curr = words.first().copied().unwrap_or(0);
+        Ones { words, base: 0, curr }
+    }
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.curr == 0 {
+            self.base += 1;
+            self.curr = *self.words.get(self.base)?;
+        }
+
+        let i = self.curr.trailing_zeros() as usize;
+        self.curr &= self.curr - 1; // Clear the lowest set bit
+        Some(self.base * 64 + i)
+    }
+}
+
+/// A fixed-size set of small integers, stored as a bitfield of `WORDS` 64-bit words.
+///
+/// A `BitSet<WORDS>` can hold the numbers from zero up to (but not including) [`BitSet::BITS`]. It is [`Copy`], and all
+/// of its operations are just a few bitwise operations per word, making it a cheap way to store "visited" sets or
+/// combinations of switches inside search states. Sets are combined with the usual operators: `|` for union, `&` for
+/// intersection, `^` for symmetric difference, and `-` for difference.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bits::BitSet;
+/// let a = BitSet::<2>::from_iter([1, 3, 100]);
+/// let b = BitSet::<2>::from_iter([3, 4, 5]);
+///
+/// assert!(a.contains(100));
+/// assert_eq!((a | b).len(), 5);
+/// assert_eq!((a & b).iter().collect::<Vec<_>>(), [3]);
+/// assert_eq!((a - b).iter().collect::<Vec<_>>(), [1, 100]);
+/// assert_eq!(format!("{:?}", a ^ b), "{1, 4, 5, 100}");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BitSet<const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    /// The number of bits in this set; one more than the largest number it can contain.
+    pub const BITS: usize = WORDS * 64;
+
+    /// Creates a new, empty set.
+    pub const fn new() -> Self {
+        BitSet { words: [0; WORDS] }
+    }
+
+    /// Creates a new set containing all of the numbers `0..n`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `n` is greater than [`BitSet::BITS`].
+    pub fn ones(n: usize) -> Self {
+        assert!(n <= Self::BITS, "bitset cannot hold more than {} bits", Self::BITS);
+        let words = std::array::from_fn(|w| match n.saturating_sub(w * 64) {
+            0 => 0,
+            k @ 1..64 => (1 << k) - 1,
+            _ => u64::MAX,
+        });
+        BitSet { words }
+    }
+
+    /// Creates a new set from its underlying words, least significant first.
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        BitSet { words }
+    }
+
+    /// Gets the underlying words of this set, least significant first.
+    pub const fn words(&self) -> &[u64; WORDS] {
+        &self.words
+    }
+
+    /// Checks whether the given number is in the set.
+    pub const fn contains(&self, i: usize) -> bool {
+        let (w, m) = locate(i);
+        w < WORDS && self.words[w] & m != 0
+    }
+
+    /// Adds a number to the set. Returns `true` if it wasn't already present.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` is not less than [`BitSet::BITS`].
+    pub fn insert(&mut self, i: usize) -> bool {
+        assert!(i < Self::BITS, "index {i} is out of range for a bitset with {} bits", Self::BITS);
+        let (w, m) = locate(i);
+        let absent = self.words[w] & m == 0;
+        self.words[w] |= m;
+        absent
+    }
+
+    /// Removes a number from the set. Returns `true` if it was present.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            let (w, m) = locate(i);
+            self.words[w] &= !m;
+        }
+        present
+    }
+
+    /// Adds a number to the set if it is absent, or removes it if it is present. Returns `true` if it is now present.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `i` is not less than [`BitSet::BITS`].
+    pub fn toggle(&mut self, i: usize) -> bool {
+        assert!(i < Self::BITS, "index {i} is out of range for a bitset with {} bits", Self::BITS);
+        let (w, m) = locate(i);
+        self.words[w] ^= m;
+        self.words[w] & m != 0
+    }
+
+    /// Removes all numbers from the set.
+    pub fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Gets the number of numbers in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Gets the smallest number in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    /// Gets the largest number in the set.
+    pub fn last(&self) -> Option<usize> {
+        self.bit_end().checked_sub(1)
+    }
+
+    /// Returns an iterator over the numbers in the set, in ascending order.
+    pub fn iter(&self) -> Ones<'_> {
+        Ones::new(&self.words)
+    }
+
+    /// Checks whether every number in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & !b == 0)
+    }
+
+    /// Checks whether every number in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Checks whether this set has no numbers in common with `other`.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+
+    /// Returns an iterator over every subset of this set (including the empty set and this set itself).
+    ///
+    /// Subsets are yielded in descending order of their underlying bits, starting with the whole set. A set of `n`
+    /// numbers has `2ⁿ` subsets, so this should only be used on small sets.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use aoc_utils::bits::BitSet;
+    /// let set = BitSet::<1>::from_iter([0, 2]);
+    /// let subsets = set.subsets().map(|s| s.iter().collect::<Vec<_>>()).collect::<Vec<_>>();
+    /// assert_eq!(subsets, [vec![0, 2], vec![2], vec![0], vec![]]);
+    /// ```
+    pub fn subsets(&self) -> impl Iterator<Item = Self> + use<WORDS> {
+        let mask = self.words;
+        let mut next = Some(*self);
+        std::iter::from_fn(move || {
+            let curr = next?;
+            if curr.is_empty() {
+                next = None;
+            } else {
+                // `(sub - 1) & mask` steps to the next-smallest submask; this is just that, across several words.
+                let mut sub = curr.words;
+                for w in &mut sub {
+                    let (d, borrow) = w.overflowing_sub(1);
+                    *w = d;
+                    if !borrow {
+                        break;
+                    }
+                }
+
+                next = Some(BitSet { words: std::array::from_fn(|i| sub[i] & mask[i]) });
+            }
+            Some(curr)
+        })
+    }
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> From<u64> for BitSet<WORDS> {
+    /// Creates a set from the bits of a single word. Bits beyond the first 64 are all left unset.
+    fn from(word: u64) -> Self {
+        let mut set = Self::new();
+        if let Some(w) = set.words.first_mut() {
+            *w = word;
+        }
+        set
+    }
+}
+
+impl<const WORDS: usize> BitMask for BitSet<WORDS> {
+    fn bit(&self, i: usize) -> bool {
+        self.contains(i)
+    }
+
+    fn bit_end(&self) -> usize {
+        words_bit_end(&self.words)
+    }
+}
+
+impl<const WORDS: usize> Debug for BitSet<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for BitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const WORDS: usize> Extend<usize> for BitSet<WORDS> {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+impl<'a, const WORDS: usize> IntoIterator for &'a BitSet<WORDS> {
+    type Item = usize;
+    type IntoIter = Ones<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const WORDS: usize> Not for BitSet<WORDS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        BitSet { words: self.words.map(|w| !w) }
+    }
+}
+
+macro_rules! impl_bitset_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, |$a:ident, $b:ident| $op:expr) => {
+        impl<const WORDS: usize> $assign_trait for BitSet<WORDS> {
+            fn $assign_method(&mut self, rhs: Self) {
+                for ($a, &$b) in self.words.iter_mut().zip(&rhs.words) {
+                    *$a = $op;
+                }
+            }
+        }
+
+        impl<const WORDS: usize> $trait for BitSet<WORDS> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.$assign_method(rhs);
+                self
+            }
+        }
+
+        impl $assign_trait<&DynBitSet> for DynBitSet {
+            fn $assign_method(&mut self, rhs: &DynBitSet) {
+                if self.words.len() < rhs.words.len() {
+                    self.words.resize(rhs.words.len(), 0);
+                }
+
+                let rhs = rhs.words.iter().copied().chain(std::iter::repeat(0));
+                for ($a, $b) in self.words.iter_mut().zip(rhs) {
+                    *$a = $op;
+                }
+
+                self.trim();
+            }
+        }
+
+        impl $trait for &DynBitSet {
+            type Output = DynBitSet;
+
+            fn $method(self, rhs: &DynBitSet) -> DynBitSet {
+                let mut set = self.clone();
+                set.$assign_method(rhs);
+                set
+            }
+        }
+    };
+}
+
+impl_bitset_op!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| *a | b);
+impl_bitset_op!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| *a & b);
+impl_bitset_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| *a ^ b);
+impl_bitset_op!(Sub, sub, SubAssign, sub_assign, |a, b| *a & !b);
+
+/// A set of small integers, stored as a bitfield which grows as needed.
+///
+/// This is the growable counterpart to [`BitSet`], for when the largest number isn't known ahead of time. Since it
+/// owns a heap allocation, its set operators work on references: `&a | &b` creates a new set, and `a |= &b` updates one
+/// in place.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::bits::DynBitSet;
+/// let mut a = DynBitSet::new();
+/// a.insert(5);
+/// a.insert(1000);
+///
+/// let b = DynBitSet::from_iter([5, 6]);
+/// assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [5]);
+///
+/// a -= &b;
+/// assert_eq!(a.iter().collect::<Vec<_>>(), [1000]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct DynBitSet {
+    /// The underlying words. There are never any trailing zero words, so that equal sets are always stored identically.
+    words: Vec<u64>,
+}
+
+impl DynBitSet {
+    /// Creates a new, empty set.
+    pub const fn new() -> Self {
+        DynBitSet { words: Vec::new() }
+    }
+
+    /// Creates a new, empty set with space for the numbers `0..n` before needing to reallocate.
+    pub fn with_capacity(n: usize) -> Self {
+        DynBitSet { words: Vec::with_capacity(n.div_ceil(64)) }
+    }
+
+    /// Removes any trailing zero words.
+    fn trim(&mut self) {
+        let len = self.words.iter().rposition(|&w| w != 0).map_or(0, |i| i + 1);
+        self.words.truncate(len);
+    }
+
+    /// Checks whether the given number is in the set.
+    pub fn contains(&self, i: usize) -> bool {
+        let (w, m) = locate(i);
+        self.words.get(w).is_some_and(|&word| word & m != 0)
+    }
+
+    /// Adds a number to the set. Returns `true` if it wasn't already present.
+    pub fn insert(&mut self, i: usize) -> bool {
+        let (w, m) = locate(i);
+        if w >= self.words.len() {
+            self.words.resize(w + 1, 0);
+        }
+
+        let absent = self.words[w] & m == 0;
+        self.words[w] |= m;
+        absent
+    }
+
+    /// Removes a number from the set. Returns `true` if it was present.
+    pub fn remove(&mut self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            let (w, m) = locate(i);
+            self.words[w] &= !m;
+            self.trim();
+        }
+        present
+    }
+
+    /// Adds a number to the set if it is absent, or removes it if it is present. Returns `true` if it is now present.
+    pub fn toggle(&mut self, i: usize) -> bool {
+        if self.remove(i) {
+            false
+        } else {
+            self.insert(i);
+            true
+        }
+    }
+
+    /// Removes all numbers from the set.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Gets the number of numbers in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Gets the smallest number in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.iter().next()
+    }
+
+    /// Gets the largest number in the set.
+    pub fn last(&self) -> Option<usize> {
+        self.bit_end().checked_sub(1)
+    }
+
+    /// Returns an iterator over the numbers in the set, in ascending order.
+    pub fn iter(&self) -> Ones<'_> {
+        Ones::new(&self.words)
+    }
+
+    /// Checks whether every number in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let other = other.words.iter().chain(std::iter::repeat(&0));
+        self.words.iter().zip(other).all(|(a, b)| a & !b == 0)
+    }
+
+    /// Checks whether every number in `other` is also in this set.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Checks whether this set has no numbers in common with `other`.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+}
+
+impl<const WORDS: usize> From<BitSet<WORDS>> for DynBitSet {
+    fn from(set: BitSet<WORDS>) -> Self {
+        let mut set = DynBitSet { words: set.words.to_vec() };
+        set.trim();
+        set
+    }
+}
+
+impl BitMask for DynBitSet {
+    fn bit(&self, i: usize) -> bool {
+        self.contains(i)
+    }
+
+    fn bit_end(&self) -> usize {
+        words_bit_end(&self.words)
+    }
+}
+
+impl Debug for DynBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for DynBitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for DynBitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DynBitSet {
+    type Item = usize;
+    type IntoIter = Ones<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,268 @@
+//! Axis-aligned boxes in any number of dimensions, and sets of them.
+//!
+//! These come up in puzzles about regions of space being switched on and off, claimed, or carved up: reactor cores made
+//! of cubes, overlapping fabric claims, rectangles fitting inside a polygon. A [`Cuboid`] is a 1D line segment, a 2D
+//! rectangle, a 3D box, and so on; a [`CuboidSet`] is an arbitrary region made up of many of them.
+
+use std::fmt::{self, Debug};
+
+use crate::math::PrimInt;
+
+/// An axis-aligned box in `N` dimensions, made up of integer coordinates.
+///
+/// Like a [`RangeInclusive`][std::ops::RangeInclusive], a cuboid's bounds are inclusive on both ends: a cuboid from
+/// `[0, 0]` to `[2, 3]` is a 3×4 rectangle containing twelve points.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::cuboid::Cuboid;
+/// let a = Cuboid::new([0, 0, 0], [2, 2, 2]);
+/// let b = Cuboid::new([1, 1, 1], [3, 3, 3]);
+///
+/// assert_eq!(a.volume(), 27);
+/// assert_eq!(a.intersection(&b), Some(Cuboid::new([1, 1, 1], [2, 2, 2])));
+///
+/// // Cutting `b` out of `a` leaves an L-shaped region of 27 - 8 = 19 cubes.
+/// let pieces = a.difference(&b);
+/// assert_eq!(pieces.iter().map(|c| c.volume()).sum::<i64>(), 19);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize, T = i64> {
+    min: [T; N],
+    max: [T; N],
+}
+
+impl<const N: usize, T: PrimInt> Cuboid<N, T> {
+    /// Creates a new cuboid with the given opposite corners (in any order).
+    pub fn new(a: [T; N], b: [T; N]) -> Self {
+        Cuboid {
+            min: std::array::from_fn(|i| a[i].min(b[i])),
+            max: std::array::from_fn(|i| a[i].max(b[i])),
+        }
+    }
+
+    /// Creates a new cuboid containing only a single point.
+    pub fn point(p: [T; N]) -> Self {
+        Cuboid { min: p, max: p }
+    }
+
+    /// The corner of this cuboid with the smallest coordinates.
+    pub fn min(&self) -> [T; N] {
+        self.min
+    }
+
+    /// The corner of this cuboid with the largest coordinates.
+    pub fn max(&self) -> [T; N] {
+        self.max
+    }
+
+    /// The length of this cuboid along each axis.
+    pub fn lengths(&self) -> [T; N] {
+        std::array::from_fn(|i| self.max[i] - self.min[i] + T::ONE)
+    }
+
+    /// The number of points in this cuboid (its length, area, volume, etc., depending on the number of dimensions).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the volume does not fit in `T`.
+    pub fn volume(&self) -> T {
+        self.lengths()
+            .into_iter()
+            .try_fold(T::ONE, |acc, len| acc.checked_mul(len))
+            .expect("attempt to compute cuboid volume with overflow")
+    }
+
+    /// Checks whether this cuboid contains the given point.
+    pub fn contains(&self, p: [T; N]) -> bool {
+        (0..N).all(|i| self.min[i] <= p[i] && p[i] <= self.max[i])
+    }
+
+    /// Checks whether this cuboid entirely contains another.
+    pub fn contains_cuboid(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.min[i] && other.max[i] <= self.max[i])
+    }
+
+    /// Checks whether this cuboid shares any points with another.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    /// Gets the region that this cuboid has in common with another, if there is one.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.intersects(other).then(|| Cuboid {
+            min: std::array::from_fn(|i| self.min[i].max(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].min(other.max[i])),
+        })
+    }
+
+    /// Cuts another cuboid out of this one, returning the leftover region as a list of non-overlapping cuboids.
+    ///
+    /// At most `2N` pieces are returned. If the two cuboids don't intersect, the only piece is a copy of `self`; if
+    /// `other` completely covers `self`, there are no pieces at all.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.intersects(other) {
+            return vec![*self];
+        }
+
+        // Go axis by axis, slicing off the slabs of what's left that stick out past either side of `other`. Once every
+        // axis is done, all that's left is the intersection, which gets thrown away.
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for i in 0..N {
+            if rest.min[i] < other.min[i] {
+                let mut slab = rest;
+                slab.max[i] = other.min[i] - T::ONE;
+                rest.min[i] = other.min[i];
+                pieces.push(slab);
+            }
+
+            if rest.max[i] > other.max[i] {
+                let mut slab = rest;
+                slab.min[i] = other.max[i] + T::ONE;
+                rest.max[i] = other.max[i];
+                pieces.push(slab);
+            }
+        }
+
+        pieces
+    }
+}
+
+impl<const N: usize, T: Debug> Debug for Cuboid<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple("Cuboid");
+        for (min, max) in self.min.iter().zip(&self.max) {
+            tuple.field(&format_args!("{min:?}..={max:?}"));
+        }
+        tuple.finish()
+    }
+}
+
+/// A region of space made up of a collection of non-overlapping [`Cuboid`]s.
+///
+/// Regions can be added with [`insert`][Self::insert] ("turned on") and taken away with [`remove`][Self::remove]
+/// ("turned off"). Since the cuboids never overlap, the total [volume][Self::volume] is just the sum of their volumes.
+///
+/// The cuboids aren't merged back together when possible, so the same region can end up represented in different ways
+/// depending on the order it was built in.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::cuboid::{Cuboid, CuboidSet};
+/// let mut set = CuboidSet::new();
+/// set.insert(Cuboid::new([10, 10, 10], [12, 12, 12]));
+/// set.insert(Cuboid::new([11, 11, 11], [13, 13, 13]));
+/// assert_eq!(set.volume(), 27 + 19);
+///
+/// set.remove(Cuboid::new([9, 9, 9], [11, 11, 11]));
+/// assert_eq!(set.volume(), 38);
+///
+/// set.insert(Cuboid::point([10, 10, 10]));
+/// assert_eq!(set.volume(), 39);
+/// assert!(set.contains([13, 13, 13]));
+/// assert!(!set.contains([11, 10, 10]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CuboidSet<const N: usize, T = i64> {
+    cuboids: Vec<Cuboid<N, T>>,
+}
+
+impl<const N: usize, T: PrimInt> CuboidSet<N, T> {
+    /// Creates a new, empty set.
+    pub fn new() -> Self {
+        CuboidSet { cuboids: Vec::new() }
+    }
+
+    /// Gets the non-overlapping cuboids that make up this set.
+    pub fn cuboids(&self) -> &[Cuboid<N, T>] {
+        &self.cuboids
+    }
+
+    /// Returns an iterator over the non-overlapping cuboids that make up this set.
+    pub fn iter(&self) -> std::slice::Iter<'_, Cuboid<N, T>> {
+        self.cuboids.iter()
+    }
+
+    /// Returns `true` if this set doesn't contain any points.
+    pub fn is_empty(&self) -> bool {
+        self.cuboids.is_empty()
+    }
+
+    /// Gets the number of cuboids that make up this set.
+    pub fn num_cuboids(&self) -> usize {
+        self.cuboids.len()
+    }
+
+    /// Gets the total number of points in this set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the volume does not fit in `T`.
+    pub fn volume(&self) -> T {
+        self.cuboids
+            .iter()
+            .try_fold(T::ZERO, |acc, c| acc.checked_add(c.volume()))
+            .expect("attempt to compute cuboid set volume with overflow")
+    }
+
+    /// Checks whether this set contains the given point.
+    pub fn contains(&self, p: [T; N]) -> bool {
+        self.cuboids.iter().any(|c| c.contains(p))
+    }
+
+    /// Adds every point in the given cuboid to this set.
+    pub fn insert(&mut self, cuboid: Cuboid<N, T>) {
+        // Clear out the space first so that nothing overlaps the new one.
+        self.remove(cuboid);
+        self.cuboids.push(cuboid);
+    }
+
+    /// Removes every point in the given cuboid from this set.
+    pub fn remove(&mut self, cuboid: Cuboid<N, T>) {
+        let mut i = 0;
+        while i < self.cuboids.len() {
+            if self.cuboids[i].intersects(&cuboid) {
+                let pieces = self.cuboids.swap_remove(i).difference(&cuboid);
+                // Any leftover pieces are appended to the end; they can't intersect `cuboid`, so they'll be skipped
+                // over when we get to them.
+                self.cuboids.extend(pieces);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Creates a new set containing only the parts of this one that fall within the given cuboid.
+    pub fn clip(&self, bounds: &Cuboid<N, T>) -> Self {
+        let cuboids = self.cuboids.iter().filter_map(|c| c.intersection(bounds)).collect();
+        CuboidSet { cuboids }
+    }
+}
+
+impl<const N: usize, T: PrimInt> Extend<Cuboid<N, T>> for CuboidSet<N, T> {
+    fn extend<I: IntoIterator<Item = Cuboid<N, T>>>(&mut self, iter: I) {
+        for cuboid in iter {
+            self.insert(cuboid);
+        }
+    }
+}
+
+impl<const N: usize, T: PrimInt> FromIterator<Cuboid<N, T>> for CuboidSet<N, T> {
+    fn from_iter<I: IntoIterator<Item = Cuboid<N, T>>>(iter: I) -> Self {
+        let mut set = CuboidSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a CuboidSet<N, T> {
+    type Item = &'a Cuboid<N, T>;
+    type IntoIter = std::slice::Iter<'a, Cuboid<N, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cuboids.iter()
+    }
+}
@@ -0,0 +1,359 @@
+//! Disjoint set unions (also known as union-find structures), and algorithms built on top of them.
+//!
+//! A disjoint set union keeps track of a collection of elements which are partitioned into non-overlapping subsets,
+//! and supports efficiently merging two subsets together and checking whether two elements belong to the same subset.
+//! They come up any time a puzzle involves things being connected together into groups (circuits, regions, networks).
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A disjoint set union.
+///
+/// This structure keeps track of some [`len`][Self::len] number of **elements** which are joined together into
+/// **subsets**. Each element is represented by an index. The subset that an element belongs to is represented by
+/// the index of the subset's **root** element.
+///
+/// Subsets are merged by size (the smaller one is attached to the root of the larger one), and paths are compressed
+/// whenever a root is looked up. Since compression doesn't change which subset anything belongs to, it's done through
+/// interior mutability; that way, lookups only need `&self`.
+///
+/// To use arbitrary values as elements instead of indices, see [`KeyedDisjointSet`].
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::disjoint::DisjointSet;
+/// let mut set = DisjointSet::with_len(5);
+/// set.join_subsets(0, 1);
+/// set.join_subsets(3, 4);
+/// set.join_subsets(1, 4);
+///
+/// assert!(set.is_joined(0, 3));
+/// assert!(!set.is_joined(0, 2));
+/// assert_eq!(set.num_sets(), 2);
+/// assert_eq!(set.size_of(4), 4);
+/// assert_eq!(set.subsets(), [vec![0, 1, 3, 4], vec![2]]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSet {
+    /// Negative values are roots, holding the (negated) size of their subset. Non-negative values are the index of the
+    /// element's parent.
+    map: Vec<Cell<isize>>,
+    num_sets: usize,
+}
+
+enum Entry {
+    Root { size: usize },
+    Child { next: usize },
+}
+
+impl DisjointSet {
+    /// Creates a new disjoint set union without any subsets.
+    pub fn new() -> Self {
+        Self { map: Vec::new(), num_sets: 0 }
+    }
+
+    /// Creates a new disjoint set union with the given number of elements, each in their own subset.
+    pub fn with_len(n: usize) -> Self {
+        assert!(n <= (isize::MAX as usize), "disjoint set union cannot hold more than isize::MAX sets");
+        Self {
+            map: vec![Cell::new(-1); n],
+            num_sets: n,
+        }
+    }
+
+    /// Returns the total number of elements across all sets in this union.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if this union has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the number of sets in this union.
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    /// Creates a new subset containing a single element.
+    ///
+    /// The index of the new element (the root of the new subset) is returned.
+    pub fn push_single(&mut self) -> usize {
+        let index = self.map.len();
+        self.map.push(Cell::new(-1));
+        self.num_sets += 1;
+        index
+    }
+
+    fn entry(&self, index: usize) -> Entry {
+        match self.map[index].get() {
+            x @ ..0 => Entry::Root { size: (-x) as usize },
+            x @ 0.. => Entry::Child { next: x as usize },
+        }
+    }
+
+    #[inline]
+    fn set_parent(&self, index: usize, parent: usize) {
+        self.map[index].set(parent as isize);
+    }
+
+    #[inline]
+    fn set_size(&self, index: usize, new_size: usize) {
+        assert!(new_size <= (isize::MAX as usize), "disjoint subset cannot be larger than isize::MAX");
+        self.map[index].set(-(new_size as isize));
+    }
+
+    /// Gets the size of the subset `index` is a part of.
+    pub fn size_of(&self, index: usize) -> usize {
+        match self.entry(self.find_root(index)) {
+            Entry::Root { size } => size,
+            Entry::Child { .. } => unreachable!("find_root should always return a root"),
+        }
+    }
+
+    /// Checks if the indices `i` and `j` belong to the same subset.
+    pub fn is_joined(&self, i: usize, j: usize) -> bool {
+        self.find_root(i) == self.find_root(j)
+    }
+
+    /// Finds the root index of the subset containing `index`, compressing the path to it along the way.
+    pub fn find_root(&self, index: usize) -> usize {
+        let mut root = index;
+        while let Entry::Child { next } = self.entry(root) {
+            root = next;
+        }
+
+        // Path compression: point everything we passed directly at the root.
+        let mut curr = index;
+        while let Entry::Child { next } = self.entry(curr) {
+            self.set_parent(curr, root);
+            curr = next;
+        }
+
+        root
+    }
+
+    /// Joins the subsets containing `i` and `j` together.
+    ///
+    /// Returns `true` if a merge was performed, and `false` if the they were already in the same set.
+    pub fn join_subsets(&mut self, i: usize, j: usize) -> bool {
+        let i = self.find_root(i);
+        let j = self.find_root(j);
+        if i == j {
+            false
+        } else {
+            // Otherwise we wish to merge. Which set is larger?
+            let si = self.size_of(i);
+            let sj = self.size_of(j);
+
+            // Keep the larger one as the root, since more things currently point to it.
+            let (from, into) = if si < sj { (i, j) } else { (j, i) };
+            self.set_parent(from, into);
+            self.set_size(into, si + sj);
+
+            self.num_sets -= 1;
+            true
+        }
+    }
+
+    /// Returns an iterator over the root indices of this union's subsets along with their sizes.
+    pub fn sizes(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..self.map.len()).filter_map(|i| match self.entry(i) {
+            Entry::Root { size } => Some((i, size)),
+            Entry::Child { next: _ } => None,
+        })
+    }
+
+    /// Returns a list of the elements in each of this union's subsets.
+    ///
+    /// Subsets are ordered by their smallest element, and the elements of each subset are in ascending order.
+    pub fn subsets(&self) -> Vec<Vec<usize>> {
+        // Map from root index to position in the output list.
+        let mut positions = HashMap::with_capacity(self.num_sets);
+        let mut subsets = Vec::<Vec<usize>>::with_capacity(self.num_sets);
+        for i in 0..self.map.len() {
+            let root = self.find_root(i);
+            let pos = *positions.entry(root).or_insert_with(|| {
+                subsets.push(Vec::with_capacity(self.size_of(root)));
+                subsets.len() - 1
+            });
+            subsets[pos].push(i);
+        }
+
+        subsets
+    }
+
+    /// Returns a list of all the elements in the same subset as `index` (including `index` itself), in ascending order.
+    pub fn members_of(&self, index: usize) -> Vec<usize> {
+        let root = self.find_root(index);
+        (0..self.map.len()).filter(|&i| self.find_root(i) == root).collect()
+    }
+}
+
+/// A disjoint set union over arbitrary hashable elements.
+///
+/// This is a thin wrapper around [`DisjointSet`] which assigns an index to each element the first time it is seen.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::disjoint::KeyedDisjointSet;
+/// let mut set = KeyedDisjointSet::new();
+/// set.join_subsets("a", "b");
+/// set.join_subsets("c", "d");
+/// set.insert("e");
+///
+/// assert!(set.is_joined(&"a", &"b"));
+/// assert!(!set.is_joined(&"a", &"c"));
+/// assert_eq!(set.num_sets(), 3);
+/// assert_eq!(set.subsets(), [vec![&"a", &"b"], vec![&"c", &"d"], vec![&"e"]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    inner: DisjointSet,
+    keys: Vec<K>,
+    indices: HashMap<K, usize>,
+}
+
+impl<K> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self {
+            inner: DisjointSet::new(),
+            keys: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    /// Creates a new disjoint set union without any elements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of elements across all sets in this union.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if this union has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the number of sets in this union.
+    pub fn num_sets(&self) -> usize {
+        self.inner.num_sets()
+    }
+
+    /// Checks whether the given element is in this union.
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Gets the index that the given element is stored at in the underlying [`DisjointSet`].
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+
+    /// Gets the element stored at the given index of the underlying [`DisjointSet`].
+    pub fn key_at(&self, index: usize) -> &K {
+        &self.keys[index]
+    }
+
+    /// Returns a reference to the underlying index-based [`DisjointSet`].
+    pub fn as_indexed(&self) -> &DisjointSet {
+        &self.inner
+    }
+
+    /// Adds an element to the union in a new subset of its own, if it is not already present. Returns the element's
+    /// index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&i) = self.indices.get(&key) {
+            return i;
+        }
+
+        let i = self.inner.push_single();
+        self.keys.push(key.clone());
+        self.indices.insert(key, i);
+        i
+    }
+
+    /// Finds the root element of the subset containing `key`, or `None` if `key` is not in the union.
+    pub fn find_root(&self, key: &K) -> Option<&K> {
+        let i = self.index_of(key)?;
+        Some(&self.keys[self.inner.find_root(i)])
+    }
+
+    /// Gets the size of the subset `key` is a part of, or `None` if `key` is not in the union.
+    pub fn size_of(&self, key: &K) -> Option<usize> {
+        self.index_of(key).map(|i| self.inner.size_of(i))
+    }
+
+    /// Checks if `a` and `b` belong to the same subset. Returns `false` if either is not in the union.
+    pub fn is_joined(&self, a: &K, b: &K) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(i), Some(j)) => self.inner.is_joined(i, j),
+            _ => false,
+        }
+    }
+
+    /// Joins the subsets containing `a` and `b` together, inserting either of them first if they are not already
+    /// present.
+    ///
+    /// Returns `true` if a merge was performed, and `false` if the they were already in the same set.
+    pub fn join_subsets(&mut self, a: K, b: K) -> bool {
+        let i = self.insert(a);
+        let j = self.insert(b);
+        self.inner.join_subsets(i, j)
+    }
+
+    /// Returns an iterator over the root elements of this union's subsets along with their sizes.
+    pub fn sizes(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.inner.sizes().map(|(i, size)| (&self.keys[i], size))
+    }
+
+    /// Returns a list of the elements in each of this union's subsets.
+    ///
+    /// Subsets and elements are ordered by when each element was first inserted.
+    pub fn subsets(&self) -> Vec<Vec<&K>> {
+        self.inner
+            .subsets()
+            .into_iter()
+            .map(|set| set.into_iter().map(|i| &self.keys[i]).collect())
+            .collect()
+    }
+}
+
+/// Finds a minimum spanning forest of an undirected, weighted graph using Kruskal's algorithm.
+///
+/// The graph has `n` nodes, indexed from `0` to `n - 1`, and its edges are given as `(a, b, weight)` triples. The edges
+/// which make up the spanning forest are returned in ascending order of weight. If the graph is connected, that forest
+/// is a single spanning tree with `n - 1` edges.
+///
+/// ```
+/// # use aoc_utils::disjoint::kruskal;
+/// let edges = [(0, 1, 4), (0, 2, 1), (1, 2, 2), (2, 3, 7), (1, 3, 5)];
+/// let tree = kruskal(4, edges);
+/// assert_eq!(tree, [(0, 2, 1), (1, 2, 2), (1, 3, 5)]);
+/// ```
+pub fn kruskal<W: Ord>(n: usize, edges: impl IntoIterator<Item = (usize, usize, W)>) -> Vec<(usize, usize, W)> {
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort_by(|(_, _, a), (_, _, b)| a.cmp(b));
+
+    let mut set = DisjointSet::with_len(n);
+    let mut tree = Vec::with_capacity(n.saturating_sub(1));
+    for (a, b, w) in edges {
+        if set.join_subsets(a, b) {
+            tree.push((a, b, w));
+            if set.num_sets() == 1 {
+                break;
+            }
+        }
+    }
+
+    tree
+}
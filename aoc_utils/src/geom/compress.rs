@@ -0,0 +1,158 @@
+use std::fmt::{self, Debug, Display};
+
+use super::{Point, Rectangle};
+use crate::grid::{Grid, Pos};
+use crate::math::Integer;
+use crate::ranges::Interval;
+
+/// Maps points with huge coordinates onto a small [`Grid`], without losing track of how much space each cell covers.
+///
+/// Each distinct _x_ value of the original points gets its own column in the compressed grid, and so does each gap
+/// between two consecutive _x_ values (the same goes for _y_ values and rows). That way, every original coordinate
+/// between the smallest and the largest lands in exactly one cell, and the cells' [real-world rectangles][Self::rect]
+/// tile the entire bounding box without overlapping. Adding up the [areas][Self::area] of a set of cells gives exactly
+/// the number of original points they cover.
+///
+/// Keeping the gaps as their own cells also means that two shapes that don't touch in the original coordinates don't
+/// touch in the compressed ones, either. If something needs to be able to go _around_ everything (e.g., a flood fill
+/// from the outside), include a point one past each corner of the bounding box to get a border of empty cells.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::geom::{CoordCompressor, Point};
+/// let points = [(2, 1), (1_000_000, 1), (1_000_000, 7), (2, 7)].map(Point::from);
+/// let comp = CoordCompressor::new(points);
+///
+/// // Columns are 2, 3..=999_999, and 1_000_000; rows are 1, 2..=6, and 7.
+/// assert_eq!((comp.width(), comp.height()), (3, 3));
+/// assert_eq!(comp.index(Point::new(1_000_000, 7)), Some((2, 2)));
+/// assert_eq!(comp.index(Point::new(500, 3)), Some((1, 1)));
+/// assert_eq!(comp.index(Point::new(0, 3)), None);
+///
+/// let grid = comp.grid(|pos, _| pos != (1, 1)); // Only the border of the rectangle
+/// let area = grid.entries().filter(|&(_, &on)| on).map(|(pos, _)| comp.area(pos)).sum::<i64>();
+/// assert_eq!(area, 999_999 * 7 - 999_997 * 5);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CoordCompressor<T = i64> {
+    cols: Vec<Interval<T>>,
+    rows: Vec<Interval<T>>,
+}
+
+impl<T: Integer> CoordCompressor<T> {
+    /// Creates a new compressor that covers the given points.
+    pub fn new(points: impl IntoIterator<Item = Point<T>>) -> Self {
+        let (xs, ys) = points.into_iter().map(|p| (p.x, p.y)).unzip();
+        CoordCompressor {
+            cols: Self::axis(xs),
+            rows: Self::axis(ys),
+        }
+    }
+
+    /// Splits a list of values into single-value cells, with one more cell for each gap between them.
+    fn axis(mut values: Vec<T>) -> Vec<Interval<T>> {
+        values.sort_unstable();
+        values.dedup();
+
+        let mut cells = Vec::with_capacity(values.len() * 2);
+        for (i, &v) in values.iter().enumerate() {
+            if i > 0 && values[i - 1] + T::ONE < v {
+                cells.push(Interval::new(values[i - 1] + T::ONE, v - T::ONE));
+            }
+            cells.push(Interval::single(v));
+        }
+        cells
+    }
+
+    /// The number of columns in the compressed grid.
+    pub fn width(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// The number of rows in the compressed grid.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Finds the column that an original _x_ coordinate belongs to.
+    ///
+    /// Returns `None` if `x` is outside of the range of _x_ values this compressor was created with.
+    pub fn col(&self, x: T) -> Option<usize> {
+        Self::find(&self.cols, x)
+    }
+
+    /// Finds the row that an original _y_ coordinate belongs to.
+    ///
+    /// Returns `None` if `y` is outside of the range of _y_ values this compressor was created with.
+    pub fn row(&self, y: T) -> Option<usize> {
+        Self::find(&self.rows, y)
+    }
+
+    fn find(cells: &[Interval<T>], v: T) -> Option<usize> {
+        let i = cells.partition_point(|c| c.end() < v);
+        (i < cells.len() && cells[i].contains(v)).then_some(i)
+    }
+
+    /// Finds the compressed grid position of a point in original coordinates.
+    ///
+    /// Returns `None` if the point is outside of this compressor's bounding box.
+    pub fn index(&self, p: Point<T>) -> Option<Pos> {
+        Some((self.col(p.x)?, self.row(p.y)?))
+    }
+
+    /// Finds the range of original _x_ coordinates covered by the given column.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `x` is out of bounds.
+    pub fn col_range(&self, x: usize) -> Interval<T> {
+        self.cols[x]
+    }
+
+    /// Finds the range of original _y_ coordinates covered by the given row.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `y` is out of bounds.
+    pub fn row_range(&self, y: usize) -> Interval<T> {
+        self.rows[y]
+    }
+
+    /// Finds the rectangle of original coordinates covered by the cell at the given compressed grid position.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `pos` is out of bounds.
+    pub fn rect(&self, (x, y): Pos) -> Rectangle<T> {
+        let (cx, cy) = (self.cols[x], self.rows[y]);
+        Rectangle::new([cx.start(), cy.start()], [cx.end(), cy.end()])
+    }
+
+    /// The number of original points covered by the cell at the given compressed grid position.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `pos` is out of bounds.
+    pub fn area(&self, (x, y): Pos) -> T {
+        self.cols[x].len() * self.rows[y].len()
+    }
+
+    /// Creates a compressed grid by calling `f` once for every cell, with its position and the
+    /// [rectangle][Self::rect] of original coordinates that it covers.
+    pub fn grid<V, F>(&self, mut f: F) -> Grid<V>
+    where
+        F: FnMut(Pos, Rectangle<T>) -> V,
+    {
+        Grid::from_fn(self.width(), self.height(), |pos| f(pos, self.rect(pos)))
+    }
+}
+
+impl<T: Display> Debug for CoordCompressor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CoordCompressor")
+            .field("cols", &self.cols)
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
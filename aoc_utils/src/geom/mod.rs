@@ -0,0 +1,229 @@
+//! Computational geometry on integer coordinates.
+//!
+//! Everything here uses exact integer arithmetic (or [`Rational`] numbers, where a result might not land on a whole
+//! number), so there are no floating-point rounding issues to worry about. Coordinates must be signed, since a lot of
+//! these algorithms work by taking cross products, which can be negative even when the coordinates themselves aren't.
+//!
+//! Like a [`Grid`][crate::Grid], the _y_ axis points downwards: "top" means smaller _y_ values.
+
+mod compress;
+mod polygon;
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+pub use self::compress::CoordCompressor;
+pub use self::polygon::{Location, Polygon};
+use crate::cuboid::Cuboid;
+use crate::math::{Integer, Rational};
+
+/// A point in 2D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A straight line segment between two [points][Point].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<T = i64> {
+    pub a: Point<T>,
+    pub b: Point<T>,
+}
+
+/// An axis-aligned rectangle. This is just a 2D [`Cuboid`], with some extra methods for convenience.
+pub type Rectangle<T = i64> = Cuboid<2, T>;
+
+/// An error that can occur when parsing a [`Point`] from a string.
+#[derive(Debug, Error)]
+pub enum ParsePointError {
+    #[error("point should contain exactly two comma-separated coordinates")]
+    WrongCount,
+    #[error("point contains an invalid coordinate: {0}")]
+    InvalidInt(#[from] ParseIntError),
+}
+
+impl<T> Point<T> {
+    /// Creates a new point.
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+/// Determines which side of the line through `a` and `b` the point `c` is on.
+///
+/// Returns [`Greater`][Ordering::Greater] if `a → b → c` turns counter-clockwise (in a _y_-up coordinate system),
+/// [`Less`][Ordering::Less] if it turns clockwise, and [`Equal`][Ordering::Equal] if the three points are collinear.
+pub fn orientation<T: Integer>(a: Point<T>, b: Point<T>, c: Point<T>) -> Ordering {
+    let cross = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+    cross.cmp(&T::ZERO)
+}
+
+impl<T: Integer> FromStr for Point<T> {
+    type Err = ParsePointError;
+
+    /// Parses a point from a string like `"x,y"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = s.split(',');
+        let (Some(x), Some(y), None) = (bits.next(), bits.next(), bits.next()) else {
+            return Err(ParsePointError::WrongCount);
+        };
+
+        let x = x.trim().parse()?;
+        let y = y.trim().parse()?;
+        Ok(Point { x, y })
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = format!("{},{}", self.x, self.y);
+        f.pad(&str)
+    }
+}
+
+impl<T> From<[T; 2]> for Point<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for [T; 2] {
+    fn from(p: Point<T>) -> Self {
+        [p.x, p.y]
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Integer> Line<T> {
+    /// Creates a new line from start and end [points][Point].
+    pub const fn new(a: Point<T>, b: Point<T>) -> Self {
+        Line { a, b }
+    }
+
+    /// Checks if this line is horizontal.
+    pub fn is_horizontal(&self) -> bool {
+        self.a.y == self.b.y
+    }
+
+    /// Checks if this line is vertical.
+    pub fn is_vertical(&self) -> bool {
+        self.a.x == self.b.x
+    }
+
+    /// Checks whether the given point lies on this line segment (including its endpoints).
+    pub fn contains(&self, p: Point<T>) -> bool {
+        orientation(self.a, self.b, p) == Ordering::Equal
+            && self.a.x.min(self.b.x) <= p.x
+            && p.x <= self.a.x.max(self.b.x)
+            && self.a.y.min(self.b.y) <= p.y
+            && p.y <= self.a.y.max(self.b.y)
+    }
+
+    /// Checks whether this line segment shares any points with another (including if they only touch at an endpoint).
+    pub fn intersects(&self, other: &Self) -> bool {
+        let o1 = orientation(self.a, self.b, other.a);
+        let o2 = orientation(self.a, self.b, other.b);
+        let o3 = orientation(other.a, other.b, self.a);
+        let o4 = orientation(other.a, other.b, self.b);
+
+        // In the general case, the segments intersect if each one's endpoints are on opposite sides of the other. The
+        // rest of the cases are when an endpoint of one lies on the other.
+        (o1 != o2 && o3 != o4 && o1 != Ordering::Equal && o2 != Ordering::Equal)
+            || self.contains(other.a)
+            || self.contains(other.b)
+            || other.contains(self.a)
+            || other.contains(self.b)
+    }
+
+    /// Finds the single point where this line segment meets another.
+    ///
+    /// Returns `None` if the segments don't intersect, or if they overlap along some length (since there's no single
+    /// point to return).
+    pub fn intersection(&self, other: &Self) -> Option<Point<Rational<T>>> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let (d1x, d1y) = (self.b.x - self.a.x, self.b.y - self.a.y);
+        let (d2x, d2y) = (other.b.x - other.a.x, other.b.y - other.a.y);
+        let denom = d1x * d2y - d1y * d2x;
+
+        if denom == T::ZERO {
+            // The segments are collinear and overlapping. If they only overlap at a single point, it has to be an
+            // endpoint of both of them.
+            let mut shared = [self.a, self.b]
+                .into_iter()
+                .filter(|&p| other.contains(p))
+                .chain([other.a, other.b].into_iter().filter(|&p| self.contains(p)));
+            let first = shared.next()?;
+            return shared.all(|p| p == first).then(|| Point::new(Rational::int(first.x), Rational::int(first.y)));
+        }
+
+        // Solve `a + t·d1 = other.a + u·d2` for `t`; then the intersection is `a + t·d1`.
+        let t_num = (other.a.x - self.a.x) * d2y - (other.a.y - self.a.y) * d2x;
+        let x = Rational::new(self.a.x * denom + t_num * d1x, denom).unwrap();
+        let y = Rational::new(self.a.y * denom + t_num * d1y, denom).unwrap();
+        Some(Point::new(x, y))
+    }
+}
+
+impl<T: Display> Debug for Line<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line({} to {})", self.a, self.b)
+    }
+}
+
+impl<T: Integer> Cuboid<2, T> {
+    /// Creates a new rectangle from two opposite corner [points][Point].
+    pub fn from_corners(p1: Point<T>, p2: Point<T>) -> Self {
+        Cuboid::new(p1.into(), p2.into())
+    }
+
+    /// The _x_ coordinate of this rectangle's left edge.
+    pub fn left(&self) -> T {
+        self.min()[0]
+    }
+
+    /// The _x_ coordinate of this rectangle's right edge.
+    pub fn right(&self) -> T {
+        self.max()[0]
+    }
+
+    /// The _y_ coordinate of this rectangle's top edge.
+    pub fn top(&self) -> T {
+        self.min()[1]
+    }
+
+    /// The _y_ coordinate of this rectangle's bottom edge.
+    pub fn bottom(&self) -> T {
+        self.max()[1]
+    }
+
+    /// The number of points across this rectangle.
+    pub fn width(&self) -> T {
+        self.lengths()[0]
+    }
+
+    /// The number of points down this rectangle.
+    pub fn height(&self) -> T {
+        self.lengths()[1]
+    }
+
+    /// The number of points in this rectangle. This is the same as its [volume][Cuboid::volume].
+    ///
+    /// Note that since the bounds are inclusive, this counts points, not the continuous area between the corners: a
+    /// rectangle from `(0, 0)` to `(2, 2)` has an "area" of 9.
+    pub fn area(&self) -> T {
+        self.volume()
+    }
+}
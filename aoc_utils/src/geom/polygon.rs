@@ -0,0 +1,263 @@
+use std::cmp::Ordering;
+
+use super::{CoordCompressor, Line, Point, Rectangle, orientation};
+use crate::math::num::gcd;
+use crate::math::{Integer, Rational};
+
+/// A simple polygon (one whose edges don't cross each other), made up of a list of vertices.
+///
+/// The polygon is closed: there is an edge from each vertex to the next, and one more from the last vertex back to the
+/// first. The vertices may go around in either direction.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::geom::{Point, Polygon};
+/// // An L-shaped polygon:
+/// //
+/// //   #####
+/// //   #...#
+/// //   #...####
+/// //   #......#
+/// //   ########
+/// let poly = Polygon::new([(0, 0), (4, 0), (4, 2), (7, 2), (7, 4), (0, 4)].map(Point::from));
+///
+/// assert!(poly.is_rectilinear());
+/// assert_eq!(poly.double_area(), 2 * (4 * 4 + 3 * 2));
+/// assert_eq!(poly.boundary_points(), 22);
+/// assert_eq!(poly.interior_points(), 12);
+/// assert_eq!(poly.lattice_points(), 34);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon<T = i64> {
+    points: Vec<Point<T>>,
+}
+
+/// Where a point is relative to a [`Polygon`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+impl<T: Integer> Polygon<T> {
+    /// Creates a new polygon from a list of vertices.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there are fewer than three vertices.
+    pub fn new(points: impl IntoIterator<Item = Point<T>>) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        assert!(points.len() >= 3, "polygon should contain at least 3 points");
+        Polygon { points }
+    }
+
+    /// Gets a list of all the vertices in this polygon.
+    pub fn points(&self) -> &[Point<T>] {
+        &self.points
+    }
+
+    /// Returns the total number of vertices in this polygon.
+    pub fn num_points(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns an iterator of all of the line segments that form this (closed) polygon.
+    pub fn edges(&self) -> impl Iterator<Item = Line<T>> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+        self.points.iter().zip(next).map(|(&a, &b)| Line::new(a, b))
+    }
+
+    /// Checks whether every edge of this polygon is either horizontal or vertical.
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|e| e.is_horizontal() || e.is_vertical())
+    }
+
+    /// Computes twice the signed area of this polygon using the shoelace formula.
+    ///
+    /// The result is positive if the vertices go around counter-clockwise (in a _y_-up coordinate system; or clockwise
+    /// when _y_ points down, as on a [`Grid`][crate::Grid]) and negative otherwise. Doubling the area keeps it an
+    /// integer.
+    pub fn double_signed_area(&self) -> T {
+        self.edges().fold(T::ZERO, |acc, Line { a, b }| acc + (a.x * b.y - b.x * a.y))
+    }
+
+    /// Computes twice the area of this polygon. Doubling the area keeps it an integer.
+    pub fn double_area(&self) -> T {
+        self.double_signed_area().abs()
+    }
+
+    /// Computes the area of this polygon.
+    pub fn area(&self) -> Rational<T> {
+        Rational::new(self.double_area(), T::ONE + T::ONE).unwrap()
+    }
+
+    /// Counts the number of integer points which lie on this polygon's edges.
+    pub fn boundary_points(&self) -> T {
+        self.edges().fold(T::ZERO, |acc, Line { a, b }| acc + gcd(b.x - a.x, b.y - a.y))
+    }
+
+    /// Counts the number of integer points strictly inside this polygon.
+    ///
+    /// This uses [Pick's theorem](https://en.wikipedia.org/wiki/Pick%27s_theorem): for a polygon whose vertices all
+    /// have integer coordinates, `A = i + b/2 - 1`, where `A` is its area, `i` is the number of integer points inside
+    /// it, and `b` is the number of integer points on its boundary.
+    pub fn interior_points(&self) -> T {
+        let two = T::ONE + T::ONE;
+        (self.double_area() - self.boundary_points() + two) / two
+    }
+
+    /// Counts the number of integer points inside or on the boundary of this polygon.
+    ///
+    /// This is the number of tiles a polygon covers when it is drawn on a grid with a path running through the middle of
+    /// its edge tiles (for example, when digging out a trench and then its interior).
+    pub fn lattice_points(&self) -> T {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Determines whether a point is inside, outside, or on the boundary of this polygon.
+    pub fn locate(&self, p: Point<T>) -> Location {
+        self.locate_scaled(p, T::ONE)
+    }
+
+    /// Checks whether a point is inside or on the boundary of this polygon.
+    pub fn contains(&self, p: Point<T>) -> bool {
+        self.locate(p) != Location::Outside
+    }
+
+    /// Determines where a point is relative to this polygon after scaling the polygon up by `scale`. This lets us check
+    /// points which fall between integer coordinates (e.g., with `scale = 2`, the point `(1, 1)` is checked as if it
+    /// were at `(0.5, 0.5)` in the original polygon).
+    fn locate_scaled(&self, p: Point<T>, scale: T) -> Location {
+        // Use the winding number: count how many times the edges of the polygon wrap around the point, by looking at
+        // each edge that crosses the horizontal line through it. Edges going upwards that pass to the right of the
+        // point count for +1, and edges going downwards count for -1 (treating each edge as half-open on the y-axis so
+        // vertices don't get counted twice).
+        let mut winding = 0i32;
+        for Line { a, b } in self.edges() {
+            let a = Point::new(a.x * scale, a.y * scale);
+            let b = Point::new(b.x * scale, b.y * scale);
+            if Line::new(a, b).contains(p) {
+                return Location::Boundary;
+            }
+
+            if a.y <= p.y && p.y < b.y && orientation(a, b, p) == Ordering::Greater {
+                winding += 1;
+            } else if b.y <= p.y && p.y < a.y && orientation(a, b, p) == Ordering::Less {
+                winding -= 1;
+            }
+        }
+
+        if winding != 0 { Location::Inside } else { Location::Outside }
+    }
+
+    /// Checks whether the given rectangle lies entirely inside this polygon (touching its boundary is allowed).
+    ///
+    /// The rectangle's bounds are treated continuously here: the rectangle from `(0, 0)` to `(2, 2)` covers the entire
+    /// square between those two points, not just the nine integer points in it.
+    ///
+    /// # Panics
+    ///
+    /// This function only supports [rectilinear][Self::is_rectilinear] polygons. It may panic (or return the wrong
+    /// answer) if the polygon has any diagonal edges.
+    pub fn contains_rect(&self, rect: &Rectangle<T>) -> bool {
+        let (l, r, t, b) = (rect.left(), rect.right(), rect.top(), rect.bottom());
+        if l == r || t == b {
+            return self.contains_axis_segment(Line::new(Point::new(l, t), Point::new(r, b)));
+        }
+
+        // If any part of the polygon's boundary passes through the inside of the rectangle, the rectangle must stick
+        // out of the polygon somewhere.
+        for edge in self.edges() {
+            let (x1, x2) = (edge.a.x.min(edge.b.x), edge.a.x.max(edge.b.x));
+            let (y1, y2) = (edge.a.y.min(edge.b.y), edge.a.y.max(edge.b.y));
+            let crosses = if edge.is_horizontal() {
+                t < y1 && y1 < b && x1 < r && x2 > l
+            } else if edge.is_vertical() {
+                l < x1 && x1 < r && y1 < b && y2 > t
+            } else {
+                panic!("polygon should only contain horizontal and vertical edges");
+            };
+
+            if crosses {
+                return false;
+            }
+        }
+
+        // Otherwise, the inside of the rectangle is either entirely inside the polygon or entirely outside of it, so we
+        // just need to check one point. The center is a good choice, since it's never on the polygon's boundary.
+        let two = T::ONE + T::ONE;
+        self.locate_scaled(Point::new(l + r, t + b), two) == Location::Inside
+    }
+
+    /// Checks whether a horizontal or vertical line segment lies entirely inside this (rectilinear) polygon.
+    fn contains_axis_segment(&self, seg: Line<T>) -> bool {
+        // Work in terms of the position along the segment and the position across it, so that horizontal and vertical
+        // segments can be handled the same way.
+        let vertical = seg.is_vertical() && !seg.is_horizontal();
+        let along = |p: Point<T>| if vertical { p.y } else { p.x };
+        let across = |p: Point<T>| if vertical { p.x } else { p.y };
+        let at = |s: T, scale: T| {
+            let c = across(seg.a) * scale;
+            if vertical { Point::new(c, s) } else { Point::new(s, c) }
+        };
+
+        let (lo, hi) = (along(seg.a).min(along(seg.b)), along(seg.a).max(along(seg.b)));
+        let c = across(seg.a);
+
+        // Find all the spots where the polygon's boundary touches the segment. Between any two of them, the segment is
+        // either entirely inside or entirely outside the polygon.
+        let mut breaks = vec![lo, hi];
+        for edge in self.edges() {
+            let (c1, c2) = (across(edge.a).min(across(edge.b)), across(edge.a).max(across(edge.b)));
+            if c1 == c2 {
+                // Parallel to the segment: only its endpoints matter.
+                if c1 == c {
+                    breaks.extend([along(edge.a), along(edge.b)].into_iter().filter(|&s| lo <= s && s <= hi));
+                }
+            } else if c1 <= c && c <= c2 {
+                // Perpendicular to the segment.
+                let s = along(edge.a);
+                if lo <= s && s <= hi {
+                    breaks.push(s);
+                }
+            }
+        }
+
+        breaks.sort_unstable();
+        breaks.dedup();
+
+        let two = T::ONE + T::ONE;
+        breaks.iter().all(|&s| self.locate(at(s, T::ONE)) != Location::Outside)
+            && breaks.windows(2).all(|w| self.locate_scaled(at(w[0] + w[1], two), two) != Location::Outside)
+    }
+
+    /// Compresses this polygon's coordinates onto a [`CoordCompressor`]'s grid.
+    ///
+    /// Polygons in puzzles sometimes span huge ranges of coordinates despite having only a few vertices. Compressing
+    /// them keeps their shape (which vertices are above, below, left, and right of which others) while making them small
+    /// enough to, for example, draw onto a [`Grid`][crate::Grid] and flood fill. The returned compressor maps between
+    /// the two coordinate systems, and knows how much of the original space each compressed cell covers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use aoc_utils::geom::{Point, Polygon, Rectangle};
+    /// let poly = Polygon::new([(10, 5), (5000, 5), (5000, 80000), (10, 80000)].map(Point::from));
+    /// let (compressed, comp) = poly.compress();
+    /// assert_eq!(compressed.points(), [(0, 0), (2, 0), (2, 2), (0, 2)].map(Point::from));
+    /// assert_eq!(comp.col_range(1), (11..=4999).into());
+    /// assert_eq!(comp.rect((2, 2)), Rectangle::point([5000, 80000]));
+    /// ```
+    pub fn compress(&self) -> (Polygon<T>, CoordCompressor<T>) {
+        let comp = CoordCompressor::new(self.points.iter().copied());
+        let to_t = |i: usize| T::from_usize(i).expect("number of compressed coordinates should fit in T");
+        let points = self.points.iter().map(|&p| {
+            let (x, y) = comp.index(p).unwrap();
+            Point::new(to_t(x), to_t(y))
+        });
+
+        (Polygon { points: points.collect() }, comp)
+    }
+}
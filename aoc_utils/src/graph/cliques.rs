@@ -0,0 +1,107 @@
+//! Finding cliques: sets of nodes which are all directly connected to one another.
+
+use super::{Graph, NodeId};
+
+/// Intersects two sorted lists of node IDs.
+fn intersect(a: &[NodeId], b: &[NodeId]) -> Vec<NodeId> {
+    let mut out = Vec::with_capacity(a.len().min(b.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                out.push(a[i]);
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+    out
+}
+
+/// The Bron–Kerbosch algorithm, with pivoting. `r` is the clique being built, `p` holds candidates which could extend
+/// it, and `x` holds nodes which could extend it but whose cliques have already been reported. `p` and `x` are sorted.
+fn bron_kerbosch(
+    adj: &[Vec<NodeId>],
+    r: &mut Vec<NodeId>,
+    mut p: Vec<NodeId>,
+    mut x: Vec<NodeId>,
+    report: &mut impl FnMut(&[NodeId]),
+) {
+    if p.is_empty() {
+        if x.is_empty() {
+            report(r);
+        }
+        return;
+    }
+
+    // Any maximal clique must contain either the pivot or one of its non-neighbours, so we only need to branch on
+    // those. Picking the pivot with the most neighbours in `p` cuts out the most branches.
+    let pivot = p
+        .iter()
+        .chain(&x)
+        .copied()
+        .max_by_key(|&u| intersect(&p, &adj[u]).len())
+        .unwrap();
+
+    let candidates = p.iter().copied().filter(|v| adj[pivot].binary_search(v).is_err()).collect::<Vec<_>>();
+    for v in candidates {
+        r.push(v);
+        bron_kerbosch(adj, r, intersect(&p, &adj[v]), intersect(&x, &adj[v]), report);
+        r.pop();
+
+        // Move `v` from `p` to `x`.
+        p.retain(|&u| u != v);
+        let i = x.binary_search(&v).unwrap_err();
+        x.insert(i, v);
+    }
+}
+
+impl<N> Graph<N> {
+    /// Finds all of the maximal cliques in this graph using the Bron–Kerbosch algorithm. A clique is maximal if no
+    /// other node could be added to it.
+    ///
+    /// All edges are treated as undirected. The IDs within each clique are sorted.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// let graph = Graph::from_iter([("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+    /// let mut cliques = graph
+    ///     .maximal_cliques()
+    ///     .into_iter()
+    ///     .map(|c| c.into_iter().map(|id| *graph.label(id)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// cliques.sort();
+    /// assert_eq!(cliques, [vec!["a", "b", "c"], vec!["c", "d"]]);
+    /// ```
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeId>> {
+        let mut cliques = Vec::new();
+        self.for_each_maximal_clique(|clique| cliques.push(clique.to_vec()));
+        cliques
+    }
+
+    /// Finds the largest clique in this graph. If there are multiple of the same size, which one is returned is
+    /// unspecified.
+    ///
+    /// All edges are treated as undirected. The IDs within the clique are sorted.
+    pub fn maximum_clique(&self) -> Vec<NodeId> {
+        let mut best = Vec::new();
+        self.for_each_maximal_clique(|clique| {
+            if clique.len() > best.len() {
+                best = clique.to_vec();
+            }
+        });
+        best
+    }
+
+    fn for_each_maximal_clique(&self, mut f: impl FnMut(&[NodeId])) {
+        let adj = self.undirected_adj();
+        let mut r = Vec::new();
+        bron_kerbosch(&adj, &mut r, self.ids().collect(), Vec::new(), &mut |clique| {
+            let mut clique = clique.to_vec();
+            clique.sort_unstable();
+            f(&clique);
+        });
+    }
+}
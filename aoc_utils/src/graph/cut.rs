@@ -0,0 +1,121 @@
+//! Global minimum cuts: the fewest edges which need to be removed to split a graph in two.
+
+use std::collections::{BinaryHeap, HashMap};
+
+use super::{Graph, NodeId};
+
+/// The result of a minimum cut search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    /// The number of edges crossing the cut.
+    pub size: usize,
+    /// The nodes on one side of the cut, in ascending order. Every other node is on the other side.
+    pub side: Vec<NodeId>,
+}
+
+impl<N> Graph<N> {
+    /// Finds a global minimum cut of this graph using the Stoer–Wagner algorithm. That is, it finds a way to split this
+    /// graph's nodes into two non-empty groups such that as few edges as possible run between the groups.
+    ///
+    /// All edges are treated as undirected. A pair of edges going in opposite directions between the same two nodes (like
+    /// the ones added by [`add_undirected_edge`][Graph::add_undirected_edge]) counts as a single undirected edge, but
+    /// parallel edges otherwise each count towards the size of the cut. Returns `None` if the graph has fewer than two
+    /// nodes.
+    ///
+    /// Stoer–Wagner is deterministic, so there is no need to repeat it over and over like Karger's algorithm. It runs in
+    /// `O(V·E·log V)` time, which is fine for the couple thousand nodes that these puzzles tend to involve.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// // Two triangles joined by a single edge.
+    /// let graph = Graph::from_iter([("a", "b"), ("b", "c"), ("c", "a"), ("x", "y"), ("y", "z"), ("z", "x"), ("c", "x")]);
+    /// let cut = graph.min_cut().unwrap();
+    /// assert_eq!(cut.size, 1);
+    /// assert_eq!(cut.side.len(), 3);
+    ///
+    /// // The same graph, but with every edge stored in both directions.
+    /// let mut graph = Graph::new();
+    /// for (a, b) in [("a", "b"), ("b", "c"), ("c", "a"), ("x", "y"), ("y", "z"), ("z", "x"), ("c", "x")] {
+    ///     graph.add_undirected_edge(a, b);
+    /// }
+    /// assert_eq!(graph.min_cut().unwrap().size, 1);
+    /// ```
+    pub fn min_cut(&self) -> Option<MinCut> {
+        let n = self.len();
+        if n < 2 {
+            return None;
+        }
+
+        // Each entry in `adj` represents a "super-node", made up of all the original nodes in `members`. Edge weights
+        // count how many original edges run between two super-nodes.
+        //
+        // An edge stored in both directions is really just one undirected edge, so the weight between two nodes is
+        // however many edges run between them in whichever direction has more.
+        let mut directed = HashMap::<(usize, usize), usize>::new();
+        for (a, b) in self.edge_ids().filter(|(a, b)| a != b) {
+            *directed.entry((a, b)).or_default() += 1;
+        }
+
+        let mut adj = vec![HashMap::<usize, usize>::new(); n];
+        for (&(a, b), &c) in &directed {
+            let w = c.max(directed.get(&(b, a)).copied().unwrap_or(0));
+            adj[a].insert(b, w);
+            adj[b].insert(a, w);
+        }
+
+        let mut members = (0..n).map(|i| vec![i]).collect::<Vec<_>>();
+        let mut active = (0..n).collect::<Vec<_>>();
+        let mut best: Option<MinCut> = None;
+
+        let mut weight = vec![0; n];
+        let mut added = vec![false; n];
+        while active.len() > 1 {
+            // Each phase adds super-nodes one at a time, always picking the one most tightly connected to those already
+            // added. The cut between the last node and everything else is then a minimum cut between the last two
+            // nodes, after which they can be merged.
+            let mut heap = BinaryHeap::new();
+            for &v in &active {
+                weight[v] = 0;
+                added[v] = false;
+                heap.push((0, v));
+            }
+
+            let (mut prev, mut last) = (usize::MAX, usize::MAX);
+            while let Some((w, v)) = heap.pop() {
+                if added[v] || w != weight[v] {
+                    continue;
+                }
+
+                added[v] = true;
+                (prev, last) = (last, v);
+                for (&u, &c) in &adj[v] {
+                    if !added[u] {
+                        weight[u] += c;
+                        heap.push((weight[u], u));
+                    }
+                }
+            }
+
+            let size = weight[last];
+            if best.as_ref().is_none_or(|best| size < best.size) {
+                let mut side = members[last].clone();
+                side.sort_unstable();
+                best = Some(MinCut { size, side });
+            }
+
+            // Merge `last` into `prev`.
+            let last_members = std::mem::take(&mut members[last]);
+            members[prev].extend(last_members);
+            for (u, c) in std::mem::take(&mut adj[last]) {
+                adj[u].remove(&last);
+                if u != prev {
+                    *adj[prev].entry(u).or_default() += c;
+                    *adj[u].entry(prev).or_default() += c;
+                }
+            }
+            active.retain(|&v| v != last);
+        }
+
+        best
+    }
+}
@@ -0,0 +1,316 @@
+//! Exporting graphs to [Graphviz](https://graphviz.org/) DOT format, for debugging.
+//!
+//! Printing out a graph's adjacency lists is rarely enough to see what's going on. Instead, dump the graph with
+//! [`ToDot::to_dot`] and render it locally with something like `dot -Tsvg graph.dot -o graph.svg`. A [`DotStyle`] can
+//! be used to highlight particular nodes, edges, or paths, and to colour components.
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::graph::Graph;
+//! use aoc_utils::graph::dot::{DotStyle, ToDot};
+//!
+//! let graph = Graph::parse_adjacency("you: a b\na: out\nb: out", ':').unwrap();
+//! let path = ["you", "a", "out"].map(|label| graph.id(&label).unwrap());
+//! let dot = graph.to_dot_with(&DotStyle::new().highlight_path(&path));
+//! assert_eq!(dot, r#"digraph {
+//!     n0 [label="you", color=red, penwidth=2];
+//!     n1 [label="a", color=red, penwidth=2];
+//!     n2 [label="b"];
+//!     n3 [label="out", color=red, penwidth=2];
+//!     n0 -> n1 [color=red, penwidth=2];
+//!     n0 -> n2;
+//!     n1 -> n3 [color=red, penwidth=2];
+//!     n2 -> n3;
+//! }
+//! "#);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Write};
+use std::hash::{BuildHasher, Hash};
+
+use super::{Graph, NodeId};
+
+/// Colours used to fill in the nodes of each component, in order. They are reused if there are more components.
+const PALETTE: [&str; 8] = [
+    "lightblue",
+    "lightpink",
+    "palegreen",
+    "khaki",
+    "plum",
+    "lightsalmon",
+    "paleturquoise",
+    "wheat",
+];
+
+/// Options controlling how a graph is rendered to DOT.
+///
+/// Nodes are referred to using whichever type the graph being rendered uses to identify them: [`NodeId`] for a
+/// [`Graph`], or the keys of an adjacency map.
+#[derive(Debug, Clone)]
+pub struct DotStyle<N> {
+    name: Option<String>,
+    undirected: bool,
+    weights: bool,
+    nodes: HashSet<N>,
+    edges: HashSet<(N, N)>,
+    components: Vec<Vec<N>>,
+}
+
+impl<N> Default for DotStyle<N> {
+    fn default() -> Self {
+        DotStyle {
+            name: None,
+            undirected: false,
+            weights: false,
+            nodes: HashSet::new(),
+            edges: HashSet::new(),
+            components: Vec::new(),
+        }
+    }
+}
+
+impl<N> DotStyle<N> {
+    /// Creates a new style with no highlighting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name given to the graph in the output.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Renders the graph as undirected. Pairs of edges going in opposite directions between the same two nodes are
+    /// drawn as a single edge.
+    pub fn undirected(mut self, undirected: bool) -> Self {
+        self.undirected = undirected;
+        self
+    }
+
+    /// Labels each edge with its weight, for graphs which have them.
+    pub fn weights(mut self, weights: bool) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Gives each group of nodes its own fill colour.
+    pub fn components(mut self, components: Vec<Vec<N>>) -> Self {
+        self.components = components;
+        self
+    }
+}
+
+impl<N: Eq + Hash + Clone> DotStyle<N> {
+    /// Highlights a single node.
+    pub fn highlight_node(mut self, node: N) -> Self {
+        self.nodes.insert(node);
+        self
+    }
+
+    /// Highlights several nodes.
+    pub fn highlight_nodes(mut self, nodes: impl IntoIterator<Item = N>) -> Self {
+        self.nodes.extend(nodes);
+        self
+    }
+
+    /// Highlights the edge from `a` to `b`.
+    pub fn highlight_edge(mut self, a: N, b: N) -> Self {
+        self.edges.insert((a, b));
+        self
+    }
+
+    /// Highlights all of the nodes along a path, and the edges between them.
+    pub fn highlight_path(mut self, path: &[N]) -> Self {
+        self.nodes.extend(path.iter().cloned());
+        self.edges.extend(path.windows(2).map(|w| (w[0].clone(), w[1].clone())));
+        self
+    }
+}
+
+/// A graph, flattened into numbered nodes and edges so it can be written out.
+struct Layout<N> {
+    nodes: Vec<N>,
+    labels: Vec<String>,
+    edges: Vec<(usize, usize, Option<String>)>,
+}
+
+impl<N: Eq + Hash + Clone> Layout<N> {
+    fn render(&self, style: &DotStyle<N>) -> String {
+        let mut colours = HashMap::new();
+        for (c, component) in style.components.iter().enumerate() {
+            for node in component {
+                colours.insert(node, PALETTE[c % PALETTE.len()]);
+            }
+        }
+
+        let mut out = String::new();
+        let (kind, arrow) = if style.undirected { ("graph", "--") } else { ("digraph", "->") };
+        match &style.name {
+            Some(name) => writeln!(out, "{kind} \"{}\" {{", escape(name)).unwrap(),
+            None => writeln!(out, "{kind} {{").unwrap(),
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let mut attrs = vec![format!("label=\"{}\"", escape(&self.labels[i]))];
+            if let Some(colour) = colours.get(node) {
+                attrs.push(format!("style=filled, fillcolor={colour}"));
+            }
+            if style.nodes.contains(node) {
+                attrs.push("color=red, penwidth=2".to_string());
+            }
+            writeln!(out, "    n{i} [{}];", attrs.join(", ")).unwrap();
+        }
+
+        // When undirected, each edge "cancels out" the next one going the opposite way.
+        let mut unpaired = HashMap::<(usize, usize), usize>::new();
+        for (a, b, weight) in &self.edges {
+            let (a, b) = (*a, *b);
+            if style.undirected {
+                if let Some(count) = unpaired.get_mut(&(b, a))
+                    && *count > 0
+                {
+                    *count -= 1;
+                    continue;
+                }
+                *unpaired.entry((a, b)).or_default() += 1;
+            }
+
+            let mut attrs = Vec::new();
+            if style.weights
+                && let Some(weight) = weight
+            {
+                attrs.push(format!("label=\"{}\"", escape(weight)));
+            }
+
+            let (na, nb) = (&self.nodes[a], &self.nodes[b]);
+            let highlighted = style.edges.contains(&(na.clone(), nb.clone()))
+                || (style.undirected && style.edges.contains(&(nb.clone(), na.clone())));
+            if highlighted {
+                attrs.push("color=red, penwidth=2".to_string());
+            }
+
+            if attrs.is_empty() {
+                writeln!(out, "    n{a} {arrow} n{b};").unwrap();
+            } else {
+                writeln!(out, "    n{a} {arrow} n{b} [{}];", attrs.join(", ")).unwrap();
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Escapes a string for use within a quoted DOT identifier.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Types which can be exported to DOT.
+///
+/// Besides [`Graph`], this is implemented for adjacency maps (a `HashMap` from each node to a list of its neighbours,
+/// optionally paired with edge weights). Their nodes are written out in sorted order, so that the output is the same
+/// from one run to the next.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use aoc_utils::graph::dot::ToDot;
+/// let map = HashMap::from([("b", vec!["c"]), ("a", vec!["b", "c"])]);
+/// assert_eq!(map.to_dot(), r#"digraph {
+///     n0 [label="a"];
+///     n1 [label="b"];
+///     n2 [label="c"];
+///     n0 -> n1;
+///     n0 -> n2;
+///     n1 -> n2;
+/// }
+/// "#);
+/// ```
+pub trait ToDot {
+    /// The type used to refer to nodes in a [`DotStyle`].
+    type Node;
+
+    /// Renders this graph to a DOT string using the given style.
+    fn to_dot_with(&self, style: &DotStyle<Self::Node>) -> String;
+
+    /// Renders this graph to a DOT string with the default style.
+    fn to_dot(&self) -> String {
+        self.to_dot_with(&DotStyle::new())
+    }
+}
+
+impl<N: Display> ToDot for Graph<N> {
+    type Node = NodeId;
+
+    fn to_dot_with(&self, style: &DotStyle<NodeId>) -> String {
+        Layout {
+            nodes: self.ids().collect(),
+            labels: self.labels().map(|n| n.to_string()).collect(),
+            edges: self.edge_ids().map(|(a, b)| (a, b, None)).collect(),
+        }
+        .render(style)
+    }
+}
+
+/// Flattens an adjacency map into a [`Layout`], numbering nodes in the order they are first seen.
+///
+/// The map's keys are visited in sorted order, so the output doesn't depend on the map's (random) iteration order.
+fn map_layout<'a, N, E, S>(
+    map: &'a HashMap<N, Vec<E>, S>,
+    split: impl Fn(&'a E) -> (&'a N, Option<String>),
+) -> Layout<N>
+where
+    N: Display + Ord + Hash + Clone,
+{
+    let mut layout = Layout { nodes: Vec::new(), labels: Vec::new(), edges: Vec::new() };
+    let mut index = HashMap::new();
+    let mut id = |node: &N, layout: &mut Layout<N>| {
+        *index.entry(node.clone()).or_insert_with(|| {
+            layout.nodes.push(node.clone());
+            layout.labels.push(node.to_string());
+            layout.nodes.len() - 1
+        })
+    };
+
+    let mut entries = map.iter().collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|&(a, _)| a);
+
+    for (a, list) in entries {
+        let a = id(a, &mut layout);
+        for edge in list {
+            let (b, weight) = split(edge);
+            let b = id(b, &mut layout);
+            layout.edges.push((a, b, weight));
+        }
+    }
+
+    layout
+}
+
+impl<N, S> ToDot for HashMap<N, Vec<N>, S>
+where
+    N: Display + Ord + Hash + Clone,
+    S: BuildHasher,
+{
+    type Node = N;
+
+    fn to_dot_with(&self, style: &DotStyle<N>) -> String {
+        map_layout(self, |b| (b, None)).render(style)
+    }
+}
+
+impl<N, C, S> ToDot for HashMap<N, Vec<(N, C)>, S>
+where
+    N: Display + Ord + Hash + Clone,
+    C: Display,
+    S: BuildHasher,
+{
+    type Node = N;
+
+    fn to_dot_with(&self, style: &DotStyle<N>) -> String {
+        map_layout(self, |(b, c)| (b, Some(c.to_string()))).render(style)
+    }
+}
@@ -0,0 +1,148 @@
+//! Maximum matchings in bipartite graphs.
+//!
+//! A bipartite graph has two groups of nodes (the "left" and the "right"), with every edge running between the two
+//! groups. A matching pairs up left nodes with right nodes along those edges, using every node at most once. Puzzles
+//! like "each rule fits some set of fields; which rule is which field?" boil down to finding a matching that pairs up
+//! every node.
+
+use std::collections::VecDeque;
+
+use super::{Graph, NodeId};
+
+/// Finds a maximum matching in a bipartite graph using the Hopcroft–Karp algorithm.
+///
+/// The graph has `adj.len()` left nodes and `num_right` right nodes, and `adj[i]` lists the right nodes that left node
+/// `i` is connected to. The returned vector holds the right node matched with each left node, if any.
+///
+/// ```
+/// # use aoc_utils::graph::hopcroft_karp;
+/// // Left node 0 can only go with right node 1, so left node 1 must take right node 0.
+/// let adj = [vec![1], vec![0, 1], vec![2]];
+/// assert_eq!(hopcroft_karp(&adj, 3), [Some(1), Some(0), Some(2)]);
+/// ```
+pub fn hopcroft_karp(adj: &[Vec<usize>], num_right: usize) -> Vec<Option<usize>> {
+    let num_left = adj.len();
+    let mut match_left = vec![None; num_left];
+    let mut match_right = vec![None; num_right];
+    let mut dist = vec![usize::MAX; num_left];
+
+    // Each round finds the length of the shortest augmenting paths with a BFS, then augments along as many
+    // vertex-disjoint paths of that length as possible with a DFS.
+    while bfs_layers(adj, &match_left, &match_right, &mut dist) {
+        for u in 0..num_left {
+            if match_left[u].is_none() {
+                augment(u, adj, &mut match_left, &mut match_right, &mut dist);
+            }
+        }
+    }
+
+    match_left
+}
+
+/// Assigns each left node its distance from an unmatched left node, alternating between unmatched and matched edges.
+/// Returns `true` if there is any augmenting path (one ending at an unmatched right node).
+fn bfs_layers(
+    adj: &[Vec<usize>],
+    match_left: &[Option<usize>],
+    match_right: &[Option<usize>],
+    dist: &mut [usize],
+) -> bool {
+    let mut queue = VecDeque::new();
+    for u in 0..adj.len() {
+        if match_left[u].is_none() {
+            dist[u] = 0;
+            queue.push_back(u);
+        } else {
+            dist[u] = usize::MAX;
+        }
+    }
+
+    let mut found = false;
+    while let Some(u) = queue.pop_front() {
+        for &v in &adj[u] {
+            match match_right[v] {
+                None => found = true,
+                Some(w) if dist[w] == usize::MAX => {
+                    dist[w] = dist[u] + 1;
+                    queue.push_back(w);
+                },
+                Some(_) => {},
+            }
+        }
+    }
+
+    found
+}
+
+/// Tries to find an augmenting path from left node `u` which follows the BFS layers, flipping the matching along it.
+fn augment(
+    u: usize,
+    adj: &[Vec<usize>],
+    match_left: &mut [Option<usize>],
+    match_right: &mut [Option<usize>],
+    dist: &mut [usize],
+) -> bool {
+    for &v in &adj[u] {
+        let ok = match match_right[v] {
+            None => true,
+            Some(w) => dist[w] == dist[u] + 1 && augment(w, adj, match_left, match_right, dist),
+        };
+
+        if ok {
+            match_left[u] = Some(v);
+            match_right[v] = Some(u);
+            return true;
+        }
+    }
+
+    // No path through this node; don't bother trying it again this round.
+    dist[u] = usize::MAX;
+    false
+}
+
+impl<N> Graph<N> {
+    /// Finds a maximum matching between the nodes for which `is_left` returns `true` and all other nodes, using the
+    /// [Hopcroft–Karp algorithm][hopcroft_karp].
+    ///
+    /// Edges are treated as undirected, and edges between two nodes on the same side are ignored. Returns the matched
+    /// pairs as `(left, right)`, ordered by the left node's ID.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// let graph = Graph::from_iter([("row", "1"), ("class", "1"), ("class", "2"), ("seat", "3"), ("seat", "1")]);
+    /// let is_left = |id| graph.label(id).parse::<u32>().is_err();
+    /// let pairs = graph
+    ///     .bipartite_matching(is_left)
+    ///     .into_iter()
+    ///     .map(|(a, b)| (*graph.label(a), *graph.label(b)))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(pairs, [("row", "1"), ("class", "2"), ("seat", "3")]);
+    /// ```
+    pub fn bipartite_matching(&self, is_left: impl Fn(NodeId) -> bool) -> Vec<(NodeId, NodeId)> {
+        // Renumber each side's nodes from zero.
+        let mut index = vec![0; self.len()];
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let sides = self.ids().map(&is_left).collect::<Vec<_>>();
+        for id in self.ids() {
+            let side = if sides[id] { &mut left } else { &mut right };
+            index[id] = side.len();
+            side.push(id);
+        }
+
+        let mut adj = vec![Vec::new(); left.len()];
+        for (a, b) in self.edge_ids() {
+            match (sides[a], sides[b]) {
+                (true, false) => adj[index[a]].push(index[b]),
+                (false, true) => adj[index[b]].push(index[a]),
+                _ => {},
+            }
+        }
+
+        hopcroft_karp(&adj, right.len())
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, m)| Some((left[i], right[m?])))
+            .collect()
+    }
+}
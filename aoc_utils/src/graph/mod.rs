@@ -0,0 +1,222 @@
+//! Data structures and algorithms for graph-based puzzles.
+//!
+//! Puzzle inputs often describe a graph as a list of lines like `aaa: bbb ccc`, mapping each node to its neighbours.
+//! [`Graph`] stores such graphs as adjacency lists. Node labels are _interned_: each distinct label is assigned a
+//! dense [`NodeId`] when it is first added, so that the algorithms in this module can work with plain indices and
+//! vectors instead of hashing labels over and over.
+
+pub mod cliques;
+pub mod cut;
+pub mod dot;
+pub mod matching;
+pub mod order;
+pub mod paths;
+pub mod search;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use thiserror::Error;
+
+pub use self::cut::MinCut;
+pub use self::matching::hopcroft_karp;
+pub use self::order::CycleError;
+pub use self::paths::{Cost, WeightedGraph};
+pub use self::search::{SearchState, Searcher};
+
+/// The index of a node within a [`Graph`].
+pub type NodeId = usize;
+
+/// A directed graph stored as a set of adjacency lists.
+///
+/// Undirected graphs can be represented by adding edges in both directions (see [`Graph::add_undirected_edge`]).
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    labels: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    adj: Vec<Vec<NodeId>>,
+}
+
+/// An error representing failure to parse a [`Graph`] from an adjacency list.
+#[derive(Error, Debug, Clone)]
+pub enum ParseGraphError {
+    /// A line of the input did not contain the separator between a node and its neighbours.
+    #[error("line {0} of graph input is missing a '{1}' separator")]
+    MissingSeparator(usize, char),
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            labels: Vec::new(),
+            ids: HashMap::new(),
+            adj: Vec::new(),
+        }
+    }
+}
+
+impl<N> Graph<N> {
+    /// Creates a new, empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of nodes in this graph.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if this graph has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Returns the total number of (directed) edges in this graph.
+    pub fn num_edges(&self) -> usize {
+        self.adj.iter().map(|list| list.len()).sum()
+    }
+
+    /// Gets the label of the node with the given ID.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `id` is not a valid node ID for this graph.
+    pub fn label(&self, id: NodeId) -> &N {
+        &self.labels[id]
+    }
+
+    /// Returns an iterator over the IDs of all nodes in this graph.
+    pub fn ids(&self) -> std::ops::Range<NodeId> {
+        0..self.labels.len()
+    }
+
+    /// Returns an iterator over the labels of all nodes in this graph, in order of ID.
+    pub fn labels(&self) -> impl Iterator<Item = &N> {
+        self.labels.iter()
+    }
+
+    /// Gets the IDs of all nodes that the given node has an edge pointing to.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `id` is not a valid node ID for this graph.
+    pub fn neighbour_ids(&self, id: NodeId) -> &[NodeId] {
+        &self.adj[id]
+    }
+
+    /// Returns an iterator over all edges in this graph, as pairs of node IDs.
+    pub fn edge_ids(&self) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.adj
+            .iter()
+            .enumerate()
+            .flat_map(|(a, list)| list.iter().map(move |&b| (a, b)))
+    }
+
+    /// Returns an iterator over all edges in this graph, as pairs of node labels.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.edge_ids().map(|(a, b)| (&self.labels[a], &self.labels[b]))
+    }
+
+    /// Builds sorted, de-duplicated adjacency lists which treat every edge as undirected and which exclude self-loops.
+    fn undirected_adj(&self) -> Vec<Vec<NodeId>> {
+        let mut adj = vec![Vec::new(); self.len()];
+        for (a, b) in self.edge_ids().filter(|(a, b)| a != b) {
+            adj[a].push(b);
+            adj[b].push(a);
+        }
+
+        for list in &mut adj {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        adj
+    }
+}
+
+impl<N: Hash + Eq + Clone> Graph<N> {
+    /// Gets the ID of the node with the given label, if it is in the graph.
+    pub fn id(&self, label: &N) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    /// Checks whether a node with the given label is in this graph.
+    pub fn contains(&self, label: &N) -> bool {
+        self.ids.contains_key(label)
+    }
+
+    /// Adds a node to the graph, returning its ID. If a node with the given label already exists, its existing ID is
+    /// returned.
+    pub fn add_node(&mut self, label: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+
+        let id = self.labels.len();
+        self.labels.push(label.clone());
+        self.ids.insert(label, id);
+        self.adj.push(Vec::new());
+        id
+    }
+
+    /// Adds a directed edge from `a` to `b`, adding either node to the graph if they are not yet present.
+    pub fn add_edge(&mut self, a: N, b: N) {
+        let a = self.add_node(a);
+        let b = self.add_node(b);
+        self.adj[a].push(b);
+    }
+
+    /// Adds an edge in both directions between `a` and `b`, adding either node to the graph if they are not yet
+    /// present.
+    pub fn add_undirected_edge(&mut self, a: N, b: N) {
+        let a = self.add_node(a);
+        let b = self.add_node(b);
+        self.adj[a].push(b);
+        self.adj[b].push(a);
+    }
+
+    /// Returns an iterator over the labels of all nodes that the given node has an edge pointing to. If the node is not
+    /// in the graph, the iterator is empty.
+    pub fn neighbours(&self, label: &N) -> impl Iterator<Item = &N> {
+        let list = self.id(label).map_or(&[][..], |id| &self.adj[id][..]);
+        list.iter().map(|&id| &self.labels[id])
+    }
+}
+
+impl<'a> Graph<&'a str> {
+    /// Parses a graph from lines of the form `node: neighbour neighbour ...`, where nodes are separated by whitespace
+    /// and the node is separated from its neighbours by `sep`. Labels borrow directly from the input string.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// let graph = Graph::parse_adjacency("a: b c\nb: c", ':').unwrap();
+    /// assert_eq!(graph.len(), 3);
+    /// assert_eq!(graph.neighbours(&"a").collect::<Vec<_>>(), [&"b", &"c"]);
+    /// ```
+    pub fn parse_adjacency(input: &'a str, sep: char) -> Result<Self, ParseGraphError> {
+        let mut graph = Graph::new();
+        for (i, line) in input.lines().enumerate() {
+            let (src, rest) = line
+                .split_once(sep)
+                .ok_or(ParseGraphError::MissingSeparator(i + 1, sep))?;
+            let src = graph.add_node(src.trim());
+            for dst in rest.split_whitespace() {
+                let dst = graph.add_node(dst);
+                graph.adj[src].push(dst);
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+impl<N: Hash + Eq + Clone> FromIterator<(N, N)> for Graph<N> {
+    /// Creates a directed graph from an iterator of edges.
+    fn from_iter<T: IntoIterator<Item = (N, N)>>(iter: T) -> Self {
+        let mut graph = Graph::new();
+        for (a, b) in iter {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+}
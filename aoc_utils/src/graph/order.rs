@@ -0,0 +1,262 @@
+//! Topological ordering, strongly connected components, and cycle detection.
+//!
+//! Rule-ordering puzzles ("page X must come before page Y") describe a graph whose edges are ordering constraints.
+//! Sorting with a comparator built from those rules only works if the rules happen to form a total order; a
+//! topological sort is correct regardless.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use thiserror::Error;
+
+use super::{Graph, NodeId};
+
+/// An error returned when attempting to order a graph that contains a cycle.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("graph contains a cycle of length {}", .cycle.len())]
+pub struct CycleError {
+    cycle: Vec<NodeId>,
+}
+
+impl CycleError {
+    /// Gets the IDs of the nodes that make up one of the graph's cycles, in order. The last node has an edge back to
+    /// the first.
+    pub fn cycle(&self) -> &[NodeId] {
+        &self.cycle
+    }
+
+    /// Consumes this error, returning the IDs of the nodes in the cycle.
+    pub fn into_cycle(self) -> Vec<NodeId> {
+        self.cycle
+    }
+}
+
+/// Runs Kahn's algorithm over the nodes `0..n`, using the given edge list. Ready nodes are always taken in ascending
+/// order, so that nodes which are not constrained relative to one another keep their original order.
+///
+/// If there is a cycle, it is returned (in terms of `0..n`) instead.
+fn kahn(n: usize, edges: &[(usize, usize)]) -> Result<Vec<usize>, Vec<usize>> {
+    let mut adj = vec![Vec::new(); n];
+    let mut in_degree = vec![0usize; n];
+    for &(a, b) in edges {
+        adj[a].push(b);
+        in_degree[b] += 1;
+    }
+
+    let mut ready = (0..n).filter(|&i| in_degree[i] == 0).map(Reverse).collect::<BinaryHeap<_>>();
+    let mut order = Vec::with_capacity(n);
+    while let Some(Reverse(a)) = ready.pop() {
+        order.push(a);
+        for &b in &adj[a] {
+            in_degree[b] -= 1;
+            if in_degree[b] == 0 {
+                ready.push(Reverse(b));
+            }
+        }
+    }
+
+    if order.len() == n {
+        return Ok(order);
+    }
+
+    // Every node that was never output still has at least one incoming edge from another node that was never output.
+    // So, walking backwards along those edges must eventually revisit a node, and the part of the walk after the first
+    // visit to that node is a cycle.
+    let mut pred = vec![None; n];
+    for &(a, b) in edges {
+        if in_degree[a] > 0 && in_degree[b] > 0 {
+            pred[b] = Some(a);
+        }
+    }
+
+    let start = (0..n).find(|&i| in_degree[i] > 0).unwrap();
+    let mut seen = vec![false; n];
+    let mut curr = start;
+    while !seen[curr] {
+        seen[curr] = true;
+        curr = pred[curr].expect("unordered node should have an unordered predecessor");
+    }
+
+    // `curr` is now on the cycle; walk it once more to collect it, then flip it to go forwards.
+    let mut cycle = vec![curr];
+    let mut node = pred[curr].unwrap();
+    while node != curr {
+        cycle.push(node);
+        node = pred[node].unwrap();
+    }
+
+    cycle.reverse();
+    Err(cycle)
+}
+
+impl<N> Graph<N> {
+    /// Sorts the nodes of this graph topologically using Kahn's algorithm, such that every edge points from an earlier
+    /// node to a later one.
+    ///
+    /// Nodes whose relative order is not constrained by any edges are kept in order of ID. If the graph contains a
+    /// cycle, there is no such ordering, and the returned error holds one of the cycles.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// let graph = Graph::from_iter([("shirt", "tie"), ("tie", "jacket"), ("shirt", "jacket"), ("pants", "shoes")]);
+    /// let order = graph.topological_sort().unwrap();
+    /// let order = order.into_iter().map(|id| *graph.label(id)).collect::<Vec<_>>();
+    /// assert_eq!(order, ["shirt", "tie", "jacket", "pants", "shoes"]);
+    ///
+    /// let graph = Graph::from_iter([("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+    /// let err = graph.topological_sort().unwrap_err();
+    /// assert_eq!(err.cycle().len(), 3);
+    /// ```
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, CycleError> {
+        kahn(self.len(), &self.edge_ids().collect::<Vec<_>>()).map_err(|cycle| CycleError { cycle })
+    }
+
+    /// Finds one of the cycles in this graph, if there are any. Self-loops count as cycles of length one.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        self.topological_sort().err().map(CycleError::into_cycle)
+    }
+
+    /// Checks whether this graph contains any cycles.
+    pub fn has_cycle(&self) -> bool {
+        self.topological_sort().is_err()
+    }
+
+    /// Finds the strongly connected components of this graph using Tarjan's algorithm.
+    ///
+    /// Every node is part of exactly one component. Components are returned in _reverse_ topological order: if there is
+    /// an edge from a node in component `A` to a node in component `B`, then `B` comes before `A`.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// let graph = Graph::from_iter([("a", "b"), ("b", "a"), ("b", "c"), ("c", "d"), ("d", "c"), ("d", "e")]);
+    /// let sccs = graph
+    ///     .strongly_connected_components()
+    ///     .into_iter()
+    ///     .map(|scc| scc.into_iter().map(|id| *graph.label(id)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sccs, [vec!["e"], vec!["d", "c"], vec!["b", "a"]]);
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Tarjan's algorithm is usually written recursively, but puzzle graphs can easily be deep enough to overflow the
+        // stack; so, we keep an explicit call stack of `(node, next neighbour index)` pairs instead.
+        let n = self.len();
+        let mut index = vec![usize::MAX; n];
+        let mut low_link = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack = Vec::new();
+        let mut calls = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        for root in self.ids() {
+            if index[root] != usize::MAX {
+                continue;
+            }
+
+            calls.push((root, 0));
+            while let Some(&(v, i)) = calls.last() {
+                if i == 0 {
+                    index[v] = next_index;
+                    low_link[v] = next_index;
+                    next_index += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                }
+
+                if let Some(&w) = self.adj[v].get(i) {
+                    calls.last_mut().unwrap().1 += 1;
+                    if index[w] == usize::MAX {
+                        calls.push((w, 0));
+                    } else if on_stack[w] {
+                        low_link[v] = low_link[v].min(index[w]);
+                    }
+                    continue;
+                }
+
+                // Done with all of `v`'s neighbours; "return" to its parent.
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low_link[parent] = low_link[parent].min(low_link[v]);
+                }
+
+                if low_link[v] == index[v] {
+                    let mut component = Vec::new();
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+}
+
+impl<N: Hash + Eq + Clone> Graph<N> {
+    /// Sorts a subset of this graph's nodes such that, for every edge `a → b` in the graph between two nodes in the
+    /// subset, `a` comes before `b`.
+    ///
+    /// This is a topological sort of the subgraph induced by `items`: edges to or from nodes outside of the subset are
+    /// ignored, so the whole graph does not need to be acyclic. Items that are not constrained relative to one another
+    /// (including items not present in the graph at all) keep their original relative order. In particular, if
+    /// `items` is already in a valid order, the result is identical to `items`.
+    ///
+    /// Each item should appear in `items` at most once. If the subset contains a cycle, the returned error holds its
+    /// node IDs.
+    ///
+    /// ```
+    /// # use aoc_utils::graph::Graph;
+    /// // A cyclic set of rules: 1 < 2 < 3 < 1.
+    /// let rules = Graph::from_iter([(1, 2), (2, 3), (3, 1), (4, 2)]);
+    /// assert_eq!(rules.sort_subset(&[2, 5, 1]).unwrap(), [5, 1, 2]);
+    /// assert_eq!(rules.sort_subset(&[3, 2, 4]).unwrap(), [4, 2, 3]);
+    /// assert!(rules.sort_subset(&[1, 2, 3]).is_err());
+    /// ```
+    pub fn sort_subset(&self, items: &[N]) -> Result<Vec<N>, CycleError> {
+        // Work in terms of positions within `items`, so that ties are broken by original order.
+        let ids = items.iter().map(|item| self.id(item)).collect::<Vec<_>>();
+        let positions = ids
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, id)| Some(((*id)?, pos)))
+            .collect::<HashMap<_, _>>();
+
+        let edges = ids
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, id)| Some((pos, (*id)?)))
+            .flat_map(|(a, id)| self.adj[id].iter().filter_map(|b| positions.get(b)).map(move |&b| (a, b)))
+            .collect::<Vec<_>>();
+
+        match kahn(items.len(), &edges) {
+            Ok(order) => Ok(order.into_iter().map(|pos| items[pos].clone()).collect()),
+            Err(cycle) => Err(CycleError {
+                cycle: cycle.into_iter().map(|pos| ids[pos].unwrap()).collect(),
+            }),
+        }
+    }
+
+    /// Checks whether a list of nodes is already in an order consistent with this graph's edges. That is, whether
+    /// there are no edges `a → b` where `b` comes before `a` in `items`.
+    pub fn is_sorted_subset(&self, items: &[N]) -> bool {
+        let positions = items
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, item)| Some((self.id(item)?, pos)))
+            .collect::<HashMap<_, _>>();
+
+        positions.iter().all(|(&a, &pa)| {
+            self.adj[a]
+                .iter()
+                .filter_map(|b| positions.get(b))
+                .all(|&pb| pa < pb)
+        })
+    }
+}
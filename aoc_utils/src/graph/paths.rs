@@ -0,0 +1,451 @@
+//! Shortest paths over weighted graphs.
+//!
+//! Besides grids, plenty of puzzles are built on abstract graphs with costs on their edges: networks of valves, routes
+//! between cities, and so on. The [`WeightedGraph`] trait abstracts over all of them, so that the same search algorithms
+//! work regardless of how the graph is actually stored. It is implemented for:
+//!
+//! - [`Grid<T>`], where every cell is connected to its four adjacent cells, and the cost of moving into a cell is the
+//!   value of that cell;
+//! - [`Graph<N>`], where every edge has a cost of 1;
+//! - `HashMap<N, Vec<(N, C)>>`, a plain map from each node to its neighbours and the costs of reaching them.
+//!
+//! Most puzzles need something more specific than those (walls, or a state that includes the current direction), in
+//! which case the trait is easy to implement on a small wrapper struct.
+//!
+//! # Example
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use aoc_utils::graph::paths::{dijkstra, floyd_warshall};
+//!
+//! let mut routes = HashMap::<&str, Vec<(&str, u32)>>::new();
+//! routes.insert("London", vec![("Dublin", 464), ("Belfast", 518)]);
+//! routes.insert("Dublin", vec![("London", 464), ("Belfast", 141)]);
+//! routes.insert("Belfast", vec![("London", 518), ("Dublin", 141)]);
+//!
+//! let from_london = dijkstra(&routes, "London");
+//! assert_eq!(from_london.distance(&"Belfast"), Some(518));
+//! assert_eq!(from_london.path_to(&"Dublin").unwrap(), ["London", "Dublin"]);
+//!
+//! let all = floyd_warshall(&routes, ["London", "Dublin", "Belfast"]);
+//! assert_eq!(all.distance(&"Belfast", &"Dublin"), Some(141));
+//! ```
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+use std::ops::Add;
+
+use thiserror::Error;
+
+use super::{Graph, NodeId};
+use crate::grid::{Grid, Pos};
+
+/// A type that can be used as the cost of traversing an edge in a [`WeightedGraph`].
+///
+/// This is implemented for all of the primitive integer types.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Debug {
+    /// The cost of not going anywhere.
+    const ZERO: Self;
+
+    /// The cost of a single step, for when every step costs the same.
+    const ONE: Self;
+}
+
+macro_rules! impl_cost {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A graph whose edges each have some cost.
+pub trait WeightedGraph {
+    /// The type used to identify the nodes in this graph.
+    type Node: Copy + Eq + Hash;
+
+    /// The type of each edge's cost.
+    type Cost: Cost;
+
+    /// Returns an iterator over all of the nodes that can be reached directly from the given node, along with the cost
+    /// of reaching each one.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, Self::Cost)>;
+}
+
+impl<T: Cost> WeightedGraph for Grid<T> {
+    type Node = Pos;
+    type Cost = T;
+
+    fn neighbours(&self, node: Pos) -> impl Iterator<Item = (Pos, T)> {
+        Grid::neighbours(self, node)
+            .into_iter()
+            .flat_map(|n| n.iter_adjacent())
+            .map(|pos| (pos, self[pos]))
+    }
+}
+
+impl<N> WeightedGraph for Graph<N> {
+    type Node = NodeId;
+    type Cost = usize;
+
+    fn neighbours(&self, node: NodeId) -> impl Iterator<Item = (NodeId, usize)> {
+        self.neighbour_ids(node).iter().map(|&id| (id, 1))
+    }
+}
+
+impl<N, C, S> WeightedGraph for HashMap<N, Vec<(N, C)>, S>
+where
+    N: Copy + Eq + Hash,
+    C: Cost,
+    S: BuildHasher,
+{
+    type Node = N;
+    type Cost = C;
+
+    fn neighbours(&self, node: N) -> impl Iterator<Item = (N, C)> {
+        self.get(&node).into_iter().flatten().copied()
+    }
+}
+
+/// The result of a single-source shortest path search: the distance from the starting node to every node reachable
+/// from it, and the paths which reach them.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    dist: HashMap<N, C>,
+    prev: HashMap<N, N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        ShortestPaths {
+            start,
+            dist: HashMap::from([(start, zero)]),
+            prev: HashMap::new(),
+        }
+    }
+
+    /// Gets the node that the search started from.
+    pub fn start(&self) -> N {
+        self.start
+    }
+
+    /// Gets the shortest distance from the starting node to the given node, or `None` if it is unreachable.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// Checks whether the given node is reachable from the starting node.
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.dist.contains_key(node)
+    }
+
+    /// Gets the shortest distances to every reachable node.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.dist
+    }
+
+    /// Reconstructs the shortest path from the starting node to the given node, including both endpoints. Returns
+    /// `None` if the node is unreachable.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![*node];
+        let mut curr = *node;
+        while let Some(&prev) = self.prev.get(&curr) {
+            path.push(prev);
+            curr = prev;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// An entry in Dijkstra's priority queue. Only the cost is used for ordering, so that nodes don't need to be [`Ord`].
+struct QueueEntry<N, C> {
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for QueueEntry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl<N, C: Ord> Eq for QueueEntry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for QueueEntry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for QueueEntry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+/// The paths found by a single-source search, plus the goal node that stopped the search early (if any).
+type SearchResult<G, C> = (ShortestPaths<<G as WeightedGraph>::Node, C>, Option<<G as WeightedGraph>::Node>);
+
+/// Runs Dijkstra's algorithm until the queue is empty or `is_goal` returns true for the node being visited. Returns the
+/// goal node, if it was found.
+fn dijkstra_impl<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> SearchResult<G, G::Cost> {
+    let mut paths = ShortestPaths::new(start, G::Cost::ZERO);
+    let mut queue = BinaryHeap::from([Reverse(QueueEntry { cost: G::Cost::ZERO, node: start })]);
+
+    while let Some(Reverse(QueueEntry { cost, node })) = queue.pop() {
+        // We might have found a better way to get here since this entry was added to the queue.
+        if paths.dist[&node] < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        for (next, step) in graph.neighbours(node) {
+            let next_cost = cost + step;
+            if paths.dist.get(&next).is_none_or(|&c| next_cost < c) {
+                paths.dist.insert(next, next_cost);
+                paths.prev.insert(next, node);
+                queue.push(Reverse(QueueEntry { cost: next_cost, node: next }));
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+/// Finds the shortest paths from `start` to every reachable node using Dijkstra's algorithm.
+///
+/// All edge costs must be non-negative; for graphs with negative costs, use [`bellman_ford`].
+///
+/// ```
+/// # use aoc_utils::grid::Grid;
+/// # use aoc_utils::graph::paths::dijkstra;
+/// let grid = Grid::from_lines_map("116\n138\n213".lines(), |c, _| c.to_digit(10).unwrap()).unwrap();
+/// let paths = dijkstra(&grid, (0, 0));
+/// assert_eq!(paths.distance(&(2, 2)), Some(7));
+/// assert_eq!(paths.path_to(&(2, 2)).unwrap(), [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]);
+/// ```
+pub fn dijkstra<G: WeightedGraph>(graph: &G, start: G::Node) -> ShortestPaths<G::Node, G::Cost> {
+    dijkstra_impl(graph, start, |_| false).0
+}
+
+/// Finds the shortest path from `start` to the nearest node for which `is_goal` returns `true`, using Dijkstra's
+/// algorithm. The search stops as soon as a goal is reached.
+///
+/// Returns the total cost of the path and the path itself (including both endpoints), or `None` if no goal is
+/// reachable.
+pub fn dijkstra_to<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<(G::Cost, Vec<G::Node>)> {
+    let (paths, goal) = dijkstra_impl(graph, start, is_goal);
+    let goal = goal?;
+    Some((paths.dist[&goal], paths.path_to(&goal)?))
+}
+
+/// Runs a breadth-first search until the queue is empty or `is_goal` returns true for the node being visited.
+fn bfs_impl<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> SearchResult<G, usize> {
+    let mut paths = ShortestPaths::new(start, 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+
+        let dist = paths.dist[&node];
+        for (next, _) in graph.neighbours(node) {
+            if let Entry::Vacant(slot) = paths.dist.entry(next) {
+                slot.insert(dist + 1);
+                paths.prev.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (paths, None)
+}
+
+/// Finds the paths with the fewest steps from `start` to every reachable node using a breadth-first search.
+///
+/// Edge costs are ignored: the resulting distances are numbers of edges.
+pub fn bfs<G: WeightedGraph>(graph: &G, start: G::Node) -> ShortestPaths<G::Node, usize> {
+    bfs_impl(graph, start, |_| false).0
+}
+
+/// Finds the path with the fewest steps from `start` to the nearest node for which `is_goal` returns `true`, using a
+/// breadth-first search. Edge costs are ignored.
+///
+/// Returns the number of steps and the path itself (including both endpoints), or `None` if no goal is reachable.
+pub fn bfs_to<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<(usize, Vec<G::Node>)> {
+    let (paths, goal) = bfs_impl(graph, start, is_goal);
+    let goal = goal?;
+    Some((paths.dist[&goal], paths.path_to(&goal)?))
+}
+
+/// An error returned when a shortest path search encounters a cycle with negative total cost. Such a cycle can be
+/// walked around forever to make paths arbitrarily short.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("graph contains a negative-cost cycle reachable from the starting node")]
+pub struct NegativeCycleError;
+
+/// Finds the shortest paths from `start` to every reachable node using the Bellman-Ford algorithm.
+///
+/// Unlike [`dijkstra`], edge costs may be negative. If a cycle with a negative total cost is reachable from `start`,
+/// shortest paths are not well defined and an error is returned.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use aoc_utils::graph::paths::bellman_ford;
+/// let graph = HashMap::from([('a', vec![('b', 4), ('c', 2)]), ('c', vec![('b', -3)])]);
+/// assert_eq!(bellman_ford(&graph, 'a').unwrap().distance(&'b'), Some(-1));
+///
+/// let graph = HashMap::from([('a', vec![('b', 1)]), ('b', vec![('a', -2)])]);
+/// assert!(bellman_ford(&graph, 'a').is_err());
+/// ```
+pub fn bellman_ford<G: WeightedGraph>(
+    graph: &G,
+    start: G::Node,
+) -> Result<ShortestPaths<G::Node, G::Cost>, NegativeCycleError> {
+    // The trait has no way to list all nodes, so find all the reachable ones (and their edges) first.
+    let reachable = bfs(graph, start);
+    let edges = reachable
+        .dist
+        .keys()
+        .flat_map(|&a| graph.neighbours(a).map(move |(b, cost)| (a, b, cost)))
+        .collect::<Vec<_>>();
+
+    let mut paths = ShortestPaths::new(start, G::Cost::ZERO);
+    let relax = |paths: &mut ShortestPaths<_, _>| {
+        let mut changed = false;
+        for &(a, b, cost) in &edges {
+            let Some(&dist_a) = paths.dist.get(&a) else {
+                continue;
+            };
+
+            let dist_b = dist_a + cost;
+            if paths.dist.get(&b).is_none_or(|&d| dist_b < d) {
+                paths.dist.insert(b, dist_b);
+                paths.prev.insert(b, a);
+                changed = true;
+            }
+        }
+        changed
+    };
+
+    // Shortest paths visit at most every node once, so after `n - 1` rounds of relaxation they're all final...
+    for _ in 1..reachable.dist.len() {
+        if !relax(&mut paths) {
+            return Ok(paths);
+        }
+    }
+
+    // ...unless there's a negative cycle, in which case they can keep getting shorter.
+    if relax(&mut paths) { Err(NegativeCycleError) } else { Ok(paths) }
+}
+
+/// The result of an all-pairs shortest path search: the distance between every pair of nodes in some set.
+#[derive(Debug, Clone)]
+pub struct AllPairs<N, C> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    dist: Vec<Option<C>>,
+}
+
+impl<N: Copy + Eq + Hash, C: Cost> AllPairs<N, C> {
+    /// Gets the nodes which distances were computed between.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Gets the shortest distance from `a` to `b`, or `None` if there is no path between them (or if either node was
+    /// not part of the search).
+    pub fn distance(&self, a: &N, b: &N) -> Option<C> {
+        let i = *self.indices.get(a)?;
+        let j = *self.indices.get(b)?;
+        self.dist[i * self.nodes.len() + j]
+    }
+
+    /// Checks whether the graph contained a cycle with negative total cost, in which case the distances are not
+    /// meaningful.
+    pub fn has_negative_cycle(&self) -> bool {
+        let n = self.nodes.len();
+        (0..n).any(|i| self.dist[i * n + i].is_some_and(|d| d < C::ZERO))
+    }
+}
+
+/// Finds the shortest distances between every pair of the given nodes using the Floyd–Warshall algorithm.
+///
+/// Only paths that pass exclusively through the given nodes are considered; edges leading to any other node are
+/// ignored. Edge costs may be negative; see [`AllPairs::has_negative_cycle`].
+pub fn floyd_warshall<G: WeightedGraph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> AllPairs<G::Node, G::Cost> {
+    let mut indices = HashMap::new();
+    let mut unique = Vec::new();
+    for node in nodes {
+        if let Entry::Vacant(slot) = indices.entry(node) {
+            slot.insert(unique.len());
+            unique.push(node);
+        }
+    }
+
+    let nodes = unique;
+    let n = nodes.len();
+    let mut dist = vec![None; n * n];
+    for (i, &a) in nodes.iter().enumerate() {
+        dist[i * n + i] = Some(G::Cost::ZERO);
+        for (b, cost) in graph.neighbours(a) {
+            if let Some(&j) = indices.get(&b) {
+                let d = &mut dist[i * n + j];
+                *d = Some(d.map_or(cost, |d: G::Cost| d.min(cost)));
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(ik) = dist[i * n + k] else {
+                continue;
+            };
+
+            for j in 0..n {
+                if let Some(kj) = dist[k * n + j] {
+                    let d = &mut dist[i * n + j];
+                    *d = Some(d.map_or(ik + kj, |d| d.min(ik + kj)));
+                }
+            }
+        }
+    }
+
+    AllPairs { nodes, indices, dist }
+}
@@ -0,0 +1,170 @@
+//! Memoized path counting over a [`Graph`].
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::{Graph, NodeId};
+
+/// Represents the current state of a search through a graph.
+///
+/// The state is updated as each node is visited, and is used to decide whether a path which reaches the destination
+/// should be counted. For example, a state could track whether or not certain nodes have been visited along the way.
+///
+/// `()` implements this trait for searches that do not need any state: every path is valid.
+pub trait SearchState<N>: Copy + Eq + Hash {
+    /// Gets an updated copy of the current state after visiting the given node.
+    fn update(self, node: &N) -> Self;
+
+    /// Determines if the search with this state counts as a valid path.
+    fn is_valid(&self) -> bool;
+}
+
+impl<N> SearchState<N> for () {
+    fn update(self, _node: &N) -> Self {}
+
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+/// A memoized, stateful graph searcher.
+///
+/// This struct counts the number of simple paths between two nodes which meet some condition, represented by some
+/// [state][SearchState].
+///
+/// Using a single searcher for multiple searches allows re-using the memoized cache across searches, even when those
+/// searches have different sources or destinations.
+///
+/// The memoization relies on the number of paths from a given node not depending on the path taken to get there (other
+/// than through the search state). That holds for directed acyclic graphs, which is what these puzzles usually involve.
+/// Graphs with cycles are still counted correctly, but only the parts of the graph that can't reach a cycle get
+/// memoized; everything else falls back to a plain depth-first search, which can be much slower.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::graph::{Graph, Searcher};
+/// let graph = Graph::parse_adjacency("a: b c\nb: d\nc: d\nd: e", ':').unwrap();
+/// let mut searcher = Searcher::<_, ()>::new(&graph);
+/// assert_eq!(searcher.count_simple_paths(&"a", &"e", ()), 2);
+/// assert_eq!(searcher.count_simple_paths(&"b", &"e", ()), 1);
+/// assert_eq!(searcher.count_simple_paths(&"a", &"d", ()), 2);
+/// ```
+///
+/// Cycles are never followed all the way around, since the paths wouldn't be simple:
+///
+/// ```
+/// # use aoc_utils::graph::{Graph, Searcher};
+/// // b→x→y→b is a cycle that can't reach e; c→d→c is one that can.
+/// let graph = Graph::parse_adjacency("a: b c\nb: x e\nx: y\ny: b\nc: d\nd: c e", ':').unwrap();
+/// let mut searcher = Searcher::<_, ()>::new(&graph);
+/// assert_eq!(searcher.count_simple_paths(&"a", &"e", ()), 2);
+/// assert_eq!(searcher.count_simple_paths(&"d", &"e", ()), 1);
+/// assert_eq!(searcher.count_simple_paths(&"c", &"e", ()), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Searcher<'a, N, S: SearchState<N>> {
+    graph: &'a Graph<N>,
+    memo: HashMap<(NodeId, NodeId, S), usize>,
+    path: HashSet<NodeId>,
+}
+
+impl<'a, N, S: SearchState<N>> Searcher<'a, N, S> {
+    /// Creates a new searcher over the given graph.
+    pub fn new(graph: &'a Graph<N>) -> Searcher<'a, N, S> {
+        Self {
+            graph,
+            memo: HashMap::default(),
+            path: HashSet::default(),
+        }
+    }
+
+    /// Returns the graph this searcher operates on.
+    pub fn graph(&self) -> &'a Graph<N> {
+        self.graph
+    }
+
+    /// Clears this searcher's memoized cache.
+    pub fn clear(&mut self) {
+        self.memo.clear();
+    }
+}
+
+impl<'a, N, S> Searcher<'a, N, S>
+where
+    N: Hash + Eq + Clone,
+    S: SearchState<N>,
+{
+    /// Finds the number of simple paths between two nodes in the searcher's graph which end in a
+    /// [valid][SearchState::is_valid] state.
+    ///
+    /// If either node is not in the graph, there are no paths.
+    pub fn count_simple_paths(&mut self, src: &N, dst: &N, state: S) -> usize {
+        match (self.graph.id(src), self.graph.id(dst)) {
+            (Some(src), Some(dst)) => self.count_from(src, dst, state).0,
+            _ => 0,
+        }
+    }
+
+    /// Finds the number of simple paths between two nodes, by ID.
+    pub fn count_simple_paths_by_id(&mut self, src: NodeId, dst: NodeId, state: S) -> usize {
+        self.count_from(src, dst, state).0
+    }
+
+    /// Counts the paths from `src` to `dst` that don't pass through any node already on the current path. Also returns
+    /// whether or not that count is safe to memoize.
+    fn count_from(&mut self, src: NodeId, dst: NodeId, state: S) -> (usize, bool) {
+        // In a graph, the number of paths *A→(B,C)→(...)→D* is equal to the number of paths from *B→D* plus the number
+        // of paths from *C→D*. However, those paths from *B* and *C* might be very similar, or even completely
+        // identical! We don't want to have to do all that searching twice. So, we'll do the DFS scan from *B→(...)→D*
+        // as normal, but for every node *N* in between, we'll store the number of *N→(...)→D* paths. Then, if/when
+        // *C→(...)→D* encounters *N*, it doesn't need to travel down that whole leg again.
+        //
+        // To handle the case of a stateful search (e.g., "have we seen `fft` and `dac` yet?") we include that state in
+        // the cache. For example, consider: *A→(B,C)→D→E* when searching for *A→E*, where we wish to encounter node
+        // *B*. When we get to *E* through *B* the first time, we want to write down the *D* was part of 1 valid path.
+        // But if we just write down *D,1*, then the second branch of the search, through *A→C→D*, will think that the
+        // *D* should be short-circuited with a 1; but it should really be zero.
+        //
+        // The destination is also part of the key, so that the cache stays correct when it's re-used for a query with
+        // a different destination. The cache is keyed on the state as it was _before_ visiting `src`, since that's
+        // what's available when checking it.
+        //
+        // Cycles break that assumption. If we've already visited a node along the way, then continuing through it
+        // wouldn't be a simple path, so it contributes nothing. But that means that the number of paths from each node
+        // that led back around to it depends on what's on the current path, so none of those counts can be cached.
+        // Anything that never runs into the current path (i.e., that can't reach a cycle) is still fair game.
+        if self.path.contains(&src) {
+            (0, false)
+        } else if let Some(&count) = self.memo.get(&(dst, src, state)) {
+            // If we already know how many paths go from this node to the destination, just return that.
+            (count, true)
+        } else {
+            let next = state.update(self.graph.label(src));
+            let (count, cacheable) = if src == dst {
+                // If we are trying to go from node A to node A, there is exactly one path: []. That is, as long as we
+                // exclude all the possible A->A cycles. Which we do, because we're counting simple paths. We also only
+                // count it when the state deems to be valid.
+                (if next.is_valid() { 1 } else { 0 }, true)
+            } else {
+                self.path.insert(src);
+
+                let mut count = 0;
+                let mut cacheable = true;
+                for &neighbour in self.graph.neighbour_ids(src) {
+                    let (n, c) = self.count_from(neighbour, dst, next);
+                    count += n;
+                    cacheable &= c;
+                }
+
+                self.path.remove(&src);
+                (count, cacheable)
+            };
+
+            if cacheable {
+                self.memo.insert((dst, src, state), count);
+            }
+            (count, cacheable)
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! A generic cellular automaton runner over a [two-dimensional grid][Grid].
+//!
+//! Plenty of puzzles boil down to "repeatedly update every cell based on its neighbours until nothing changes" (Game of
+//! Life variants, erosion, removing reachable items, and so on). [`Automaton`] takes care of the bookkeeping: it
+//! double-buffers the grid so that every cell in a step sees the same previous generation, counts how many cells
+//! changed in each step, and can optionally skip over cells that could not possibly have changed.
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::grid::Grid;
+//! use aoc_utils::grid::automaton::{Automaton, Neighbourhood};
+//!
+//! // A horizontal "blinker" from Conway's Game of Life.
+//! let grid = Grid::from_lines_map([".....", ".....", ".###.", ".....", "....."], |c, _| c == '#').unwrap();
+//!
+//! let mut life = Automaton::new(grid, Neighbourhood::Around, |&alive, around| {
+//!     let n = around.filter(|&&c| c).count();
+//!     n == 3 || (alive && n == 2)
+//! });
+//!
+//! assert_eq!(life.step(), 4); // Two cells die and two are born.
+//! assert!(life.grid()[(2, 1)] && life.grid()[(2, 2)] && life.grid()[(2, 3)]);
+//! assert_eq!(life.step(), 4); // ...and back again.
+//! assert_eq!(life.changes(), &[4, 4]);
+//! ```
+
+use std::iter::FusedIterator;
+
+use super::neighbours::{IterAdjacent, IterAround};
+use super::{Grid, Pos};
+
+/// Which cells are considered to be the neighbours of a given cell when running an [`Automaton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cells directly above, below, left and right of a cell (a von Neumann neighbourhood).
+    Adjacent,
+    /// All eight cells surrounding a cell, including corners (a Moore neighbourhood).
+    Around,
+}
+
+/// A cellular automaton that repeatedly applies a rule to every cell of a [`Grid`].
+///
+/// The rule is given a reference to a cell's current value and an [iterator over the values of its
+/// neighbours][NeighbourValues], and returns the cell's value for the next generation. All cells are updated
+/// simultaneously: the new generation is written to a second buffer which is then swapped with the first.
+pub struct Automaton<T, F> {
+    front: Grid<T>,
+    back: Grid<T>,
+    rule: F,
+    neighbourhood: Neighbourhood,
+    /// Which cells need to be revisited in the next step, when tracking changes. `None` means "all of them".
+    active: Option<Vec<Pos>>,
+    /// Per-cell flags used to de-duplicate `active`. Only allocated when tracking changes.
+    marked: Option<Grid<bool>>,
+    track_changes: bool,
+    changes: Vec<usize>,
+}
+
+impl<T, F> Automaton<T, F>
+where
+    T: Clone + PartialEq,
+    F: FnMut(&T, NeighbourValues<'_, T>) -> T,
+{
+    /// Creates a new automaton which will apply `rule` to the given starting grid.
+    pub fn new(grid: Grid<T>, neighbourhood: Neighbourhood, rule: F) -> Self {
+        let back = grid.clone();
+        Automaton {
+            front: grid,
+            back,
+            rule,
+            neighbourhood,
+            active: None,
+            marked: None,
+            track_changes: false,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Configures whether this automaton should only revisit the cells around the ones which changed in the previous
+    /// step.
+    ///
+    /// This is only valid when a cell's next value depends entirely on its current value and those of its neighbours
+    /// (i.e., when a cell whose neighbourhood did not change would not change either). It can speed things up
+    /// considerably for grids where only a small "front" of cells change in each step.
+    pub fn track_changes(mut self, enable: bool) -> Self {
+        self.track_changes = enable;
+        self.marked = enable.then(|| Grid::from_elem(self.front.width(), self.front.height(), false));
+        self
+    }
+
+    /// Returns a reference to the current generation of the grid.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.front
+    }
+
+    /// Consumes this automaton and returns the current generation of the grid.
+    pub fn into_grid(self) -> Grid<T> {
+        self.front
+    }
+
+    /// Returns the number of steps that have been run so far.
+    pub fn generation(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns the number of cells that changed during each of the steps run so far.
+    pub fn changes(&self) -> &[usize] {
+        &self.changes
+    }
+
+    /// Advances the automaton by a single generation, returning the number of cells which changed.
+    pub fn step(&mut self) -> usize {
+        let num_changed = if !self.track_changes {
+            let mut n = 0;
+            for pos in self.front.positions() {
+                if self.update(pos) {
+                    n += 1;
+                }
+            }
+            std::mem::swap(&mut self.front, &mut self.back);
+            n
+        } else {
+            let active = self.active.take().unwrap_or_else(|| self.front.positions().collect());
+
+            let mut changed = Vec::new();
+            for pos in active {
+                if self.update(pos) {
+                    changed.push(pos);
+                }
+            }
+
+            std::mem::swap(&mut self.front, &mut self.back);
+
+            // Cells that were not revisited were left alone in both buffers, but the ones that changed are now stale in
+            // the back buffer.
+            for &pos in &changed {
+                self.back[pos] = self.front[pos].clone();
+            }
+
+            self.active = Some(self.next_active(&changed));
+            changed.len()
+        };
+
+        self.changes.push(num_changed);
+        num_changed
+    }
+
+    /// Runs `n` steps of the automaton, returning the total number of cells which changed.
+    pub fn run(&mut self, n: usize) -> usize {
+        (0..n).map(|_| self.step()).sum()
+    }
+
+    /// Runs the automaton until it reaches a fixed point (i.e., until a step causes no cells to change). Returns the
+    /// number of steps which caused changes.
+    ///
+    /// Note that this function will never return if the automaton settles into a cycle instead of a fixed point.
+    pub fn run_until_stable(&mut self) -> usize {
+        let mut n = 0;
+        while self.step() > 0 {
+            n += 1;
+        }
+        n
+    }
+
+    /// Computes the next value for the cell at `pos`, writing it into the back buffer. Returns `true` if the value
+    /// changed.
+    fn update(&mut self, pos: Pos) -> bool {
+        let values = NeighbourValues::new(&self.front, pos, self.neighbourhood);
+        let old = &self.front[pos];
+        let new = (self.rule)(old, values);
+        let changed = new != *old;
+        self.back[pos] = new;
+        changed
+    }
+
+    /// Gathers all of the given positions and their neighbours into a de-duplicated list.
+    fn next_active(&mut self, changed: &[Pos]) -> Vec<Pos> {
+        let (w, h) = self.front.size();
+        let marked = self.marked.get_or_insert_with(|| Grid::from_elem(w, h, false));
+
+        let mut active = Vec::with_capacity(changed.len() * 9);
+        for &pos in changed {
+            let nb = NeighbourValues::positions(&self.front, pos, self.neighbourhood);
+            for p in std::iter::once(pos).chain(nb) {
+                if !marked[p] {
+                    marked[p] = true;
+                    active.push(p);
+                }
+            }
+        }
+
+        for &p in &active {
+            marked[p] = false;
+        }
+
+        active
+    }
+}
+
+/// An iterator over references to the values of the cells neighbouring a given cell in a [`Grid`].
+///
+/// This struct is passed to the rule function of an [`Automaton`].
+#[derive(Debug, Clone)]
+pub struct NeighbourValues<'a, T> {
+    grid: &'a Grid<T>,
+    inner: NeighbourPositions,
+}
+
+#[derive(Debug, Clone)]
+enum NeighbourPositions {
+    Adjacent(IterAdjacent<Pos>),
+    Around(IterAround<Pos>),
+}
+
+impl Iterator for NeighbourPositions {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            NeighbourPositions::Adjacent(iter) => iter.next(),
+            NeighbourPositions::Around(iter) => iter.next(),
+        }
+    }
+}
+
+impl<'a, T> NeighbourValues<'a, T> {
+    fn new(grid: &'a Grid<T>, pos: Pos, neighbourhood: Neighbourhood) -> Self {
+        let inner = Self::positions(grid, pos, neighbourhood);
+        NeighbourValues { grid, inner }
+    }
+
+    fn positions(grid: &Grid<T>, pos: Pos, neighbourhood: Neighbourhood) -> NeighbourPositions {
+        let neighbours = grid.neighbours(pos).expect("automaton positions should be in bounds");
+        match neighbourhood {
+            Neighbourhood::Adjacent => NeighbourPositions::Adjacent(neighbours.iter_adjacent()),
+            Neighbourhood::Around => NeighbourPositions::Around(neighbours.iter_around()),
+        }
+    }
+}
+
+impl<'a, T> Iterator for NeighbourValues<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.inner.next()?;
+        Some(&self.grid[pos])
+    }
+}
+
+impl<'a, T> FusedIterator for NeighbourValues<'a, T> {}
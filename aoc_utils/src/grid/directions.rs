@@ -7,7 +7,9 @@
 //! The primary purpose of [`Dir4`] and [`Dir8`] from this module are to avoid having to redefine a new enum and any
 //! associated helper methods every single time a new puzzle involves directions.
 
+use std::collections::{HashMap, HashSet};
 use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
 use std::iter::{DoubleEndedIterator, ExactSizeIterator, FusedIterator, Iterator};
 use std::ops::Neg;
 use std::str::FromStr;
@@ -35,6 +37,24 @@ pub trait Direction: Copy + Into<Dir8> {
     /// Positive values represent "down" or "south".
     fn y_offset(&self) -> Offset;
 
+    /// Returns the direction that is 90° to the right of this one.
+    fn right90(self) -> Self {
+        let (x, y) = (self.x_offset().as_isize(), self.y_offset().as_isize());
+        find_by_offsets(-y, x)
+    }
+
+    /// Returns the direction that is 90° to the left of this one.
+    fn left90(self) -> Self {
+        let (x, y) = (self.x_offset().as_isize(), self.y_offset().as_isize());
+        find_by_offsets(y, -x)
+    }
+
+    /// Returns the direction pointing in the opposite direction as this one (180°).
+    fn behind(self) -> Self {
+        let (x, y) = (self.x_offset().as_isize(), self.y_offset().as_isize());
+        find_by_offsets(-x, -y)
+    }
+
     /// Converts this direction into a [`Dir8`], which is the highest "resolution" direction.
     fn into_dir8(self) -> Dir8 {
         self.into()
@@ -64,6 +84,17 @@ pub trait Direction: Copy + Into<Dir8> {
     }
 }
 
+/// Finds the direction with the given offsets, for [`Direction`]'s default methods.
+///
+/// # Panics
+///
+/// This function panics if none of the type's directions have those offsets.
+fn find_by_offsets<D: Direction>(x: isize, y: isize) -> D {
+    D::iter()
+        .find(|d| d.x_offset().as_isize() == x && d.y_offset().as_isize() == y)
+        .expect("a direction's rotations should all be directions of the same type")
+}
+
 /// An offset of either +1, -1, or 0.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Offset {
@@ -162,6 +193,18 @@ impl Direction for Dir4 {
             Dir4::Left | Dir4::Right => Offset::Zero,
         }
     }
+
+    fn right90(self) -> Self {
+        self.right()
+    }
+
+    fn left90(self) -> Self {
+        self.left()
+    }
+
+    fn behind(self) -> Self {
+        Dir4::behind(self)
+    }
 }
 
 impl Direction for Dir8 {
@@ -186,6 +229,18 @@ impl Direction for Dir8 {
             Dir8::Down | Dir8::DownLeft | Dir8::DownRight => Offset::Positive,
         }
     }
+
+    fn right90(self) -> Self {
+        Dir8::right90(self)
+    }
+
+    fn left90(self) -> Self {
+        Dir8::left90(self)
+    }
+
+    fn behind(self) -> Self {
+        Dir8::behind(self)
+    }
 }
 
 impl From<Dir4> for Dir8 {
@@ -385,13 +440,13 @@ impl Dir8 {
     /// Returns the direction that is 45° (one step) to the left of this one.
     pub const fn left45(self) -> Self {
         match self {
-            Dir8::Up => Dir8::UpRight,
-            Dir8::UpRight => Dir8::Right,
-            Dir8::Right => Dir8::DownRight,
-            Dir8::DownRight => Dir8::Down,
-            Dir8::Down => Dir8::DownLeft,
-            Dir8::DownLeft => Dir8::Left,
-            Dir8::Left => Dir8::UpLeft,
+            Dir8::Up => Dir8::UpLeft,
+            Dir8::UpRight => Dir8::Up,
+            Dir8::Right => Dir8::UpRight,
+            Dir8::DownRight => Dir8::Right,
+            Dir8::Down => Dir8::DownRight,
+            Dir8::DownLeft => Dir8::Down,
+            Dir8::Left => Dir8::DownLeft,
             Dir8::UpLeft => Dir8::Left,
         }
     }
@@ -443,14 +498,14 @@ impl Dir8 {
                 (Dir8::Up, 5) => Dir8::DownLeft,
                 (Dir8::Up, 6) => Dir8::Left,
                 (Dir8::Up, 7) => Dir8::UpLeft,
-                (Dir8::UpRight, 0) => Dir8::Right,
-                (Dir8::UpRight, 1) => Dir8::DownRight,
-                (Dir8::UpRight, 2) => Dir8::Down,
-                (Dir8::UpRight, 3) => Dir8::DownLeft,
-                (Dir8::UpRight, 4) => Dir8::Left,
-                (Dir8::UpRight, 5) => Dir8::UpLeft,
-                (Dir8::UpRight, 6) => Dir8::Up,
-                (Dir8::UpRight, 7) => Dir8::UpRight,
+                (Dir8::UpRight, 0) => Dir8::UpRight,
+                (Dir8::UpRight, 1) => Dir8::Right,
+                (Dir8::UpRight, 2) => Dir8::DownRight,
+                (Dir8::UpRight, 3) => Dir8::Down,
+                (Dir8::UpRight, 4) => Dir8::DownLeft,
+                (Dir8::UpRight, 5) => Dir8::Left,
+                (Dir8::UpRight, 6) => Dir8::UpLeft,
+                (Dir8::UpRight, 7) => Dir8::Up,
                 (Dir8::Right, 0) => Dir8::Right,
                 (Dir8::Right, 1) => Dir8::DownRight,
                 (Dir8::Right, 2) => Dir8::Down,
@@ -672,13 +727,81 @@ pub struct DirFromCharError;
 /// An error representing failure to parse a string into a [`Dir4`] or [`Dir8`].
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum ParseDirError {
-    /// The string is either too long or too short to be parsed into a direction.
-    #[error("string must be exactly 1 character")]
+    /// The string is neither a single character nor the name of a direction.
+    #[error("string must be exactly 1 character or the name of a direction")]
     InvalidLength,
 
     /// The parsed character does not represent a direction.
     #[error("character '{}' (U+{:04X}) is not a valid direction", .0, *(.0) as u32)]
     InvalidChar(char),
+
+    /// The string names a direction that can't be represented by the type being parsed (i.e., a diagonal being parsed
+    /// as a [`Dir4`]).
+    #[error("direction {0} cannot be represented by this type")]
+    NotRepresentable(Dir8),
+}
+
+/// An error representing failure to convert a [`Dir8`] into a [`Dir4`].
+#[derive(Debug, PartialEq, Eq, Error)]
+#[error("diagonal direction cannot be converted to Dir4")]
+pub struct DiagonalDirError;
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = DiagonalDirError;
+
+    fn try_from(value: Dir8) -> Result<Self, Self::Error> {
+        match value {
+            Dir8::Up => Ok(Dir4::Up),
+            Dir8::Right => Ok(Dir4::Right),
+            Dir8::Down => Ok(Dir4::Down),
+            Dir8::Left => Ok(Dir4::Left),
+            _ => Err(DiagonalDirError),
+        }
+    }
+}
+
+/// Parses the name of a direction, either as a compass direction (`N`, `NE`, `north`, `north-east`) or relative to the
+/// screen (`up`, `up-right`). Case is ignored, and words may be separated by a hyphen, underscore, space, or nothing.
+fn parse_dir_name(s: &str) -> Option<Dir8> {
+    let name = s
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | ' '))
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<String>();
+
+    match name.as_str() {
+        "n" | "north" | "up" => Some(Dir8::Up),
+        "ne" | "northeast" | "upright" => Some(Dir8::UpRight),
+        "e" | "east" | "right" => Some(Dir8::Right),
+        "se" | "southeast" | "downright" => Some(Dir8::DownRight),
+        "s" | "south" | "down" => Some(Dir8::Down),
+        "sw" | "southwest" | "downleft" => Some(Dir8::DownLeft),
+        "w" | "west" | "left" => Some(Dir8::Left),
+        "nw" | "northwest" | "upleft" => Some(Dir8::UpLeft),
+        _ => None,
+    }
+}
+
+/// Parses a string as either a single character (using the given `TryFrom<char>` implementation) or the name of a
+/// direction.
+fn parse_dir_str<D>(s: &str) -> Result<D, ParseDirError>
+where
+    D: TryFrom<char> + TryFrom<Dir8>,
+{
+    let mut chars = s.chars();
+    let c = chars.next().ok_or(ParseDirError::InvalidLength)?;
+    if chars.next().is_none() {
+        // Single characters get a chance to be parsed as a glyph before trying as a name (i.e., "N").
+        if let Ok(dir) = D::try_from(c) {
+            return Ok(dir);
+        }
+    }
+
+    match parse_dir_name(s) {
+        Some(dir) => D::try_from(dir).or(Err(ParseDirError::NotRepresentable(dir))),
+        None if s.chars().count() == 1 => Err(ParseDirError::InvalidChar(c)),
+        None => Err(ParseDirError::InvalidLength),
+    }
 }
 
 impl TryFrom<char> for Dir4 {
@@ -695,20 +818,142 @@ impl TryFrom<char> for Dir4 {
     }
 }
 
+impl TryFrom<char> for Dir8 {
+    type Error = DirFromCharError;
+
+    /// Converts an arrow glyph (`^`, `>`, `↗`, etc.) or a numpad digit (`8` for up, `9` for up-right, etc.) into a
+    /// direction.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | '↑' | '8' => Ok(Dir8::Up),
+            '↗' | '9' => Ok(Dir8::UpRight),
+            '>' | '→' | '6' => Ok(Dir8::Right),
+            '↘' | '3' => Ok(Dir8::DownRight),
+            'v' | 'V' | '↓' | '2' => Ok(Dir8::Down),
+            '↙' | '1' => Ok(Dir8::DownLeft),
+            '<' | '←' | '4' => Ok(Dir8::Left),
+            '↖' | '7' => Ok(Dir8::UpLeft),
+            _ => Err(DirFromCharError),
+        }
+    }
+}
+
 impl FromStr for Dir4 {
     type Err = ParseDirError;
 
+    /// Parses a direction from a single arrow character (see [`Dir4`]'s [`TryFrom<char>`] implementation) or from the
+    /// name of a direction (`N`, `north`, `up`, etc.).
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut chars = s.chars();
-        let c = chars.next().ok_or(ParseDirError::InvalidLength)?;
-        if chars.count() > 0 {
-            Err(ParseDirError::InvalidLength)
-        } else {
-            c.try_into().or(Err(ParseDirError::InvalidChar(c)))
-        }
+        parse_dir_str(s)
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    /// Parses a direction from a single arrow character or numpad digit (see [`Dir8`]'s [`TryFrom<char>`]
+    /// implementation) or from the name of a direction (`NE`, `north-east`, `up-right`, etc.).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_dir_str(s)
+    }
+}
+
+/// A configurable mapping from arbitrary characters to directions.
+///
+/// The [`TryFrom<char>`] implementations on [`Dir4`] and [`Dir8`] cover the most common arrow glyphs, but some puzzles
+/// invent their own alphabet for directions (`UDLR`, `NESW`, numbers, etc.). This struct handles those.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::grid::Dir4;
+/// # use aoc_utils::grid::directions::DirectionParser;
+/// let parser = DirectionParser::new()
+///     .with('U', Dir4::Up)
+///     .with('D', Dir4::Down)
+///     .with('L', Dir4::Left)
+///     .with('R', Dir4::Right);
+///
+/// assert_eq!(parser.parse('L'), Ok(Dir4::Left));
+/// assert_eq!(parser.parse_str("UR DL"), Ok(vec![Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left]));
+/// assert!(parser.parse('^').is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirectionParser<D: Direction> {
+    map: HashMap<char, D>,
+}
+
+impl<D: Direction> Default for DirectionParser<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Direction> DirectionParser<D> {
+    /// Creates a new parser which does not recognize any characters.
+    pub fn new() -> Self {
+        DirectionParser { map: HashMap::new() }
+    }
+
+    /// Adds a new character to this parser, returning the parser for chaining.
+    pub fn with(mut self, c: char, dir: D) -> Self {
+        self.insert(c, dir);
+        self
+    }
+
+    /// Adds a new character to this parser, returning the direction that character previously mapped to (if any).
+    pub fn insert(&mut self, c: char, dir: D) -> Option<D> {
+        self.map.insert(c, dir)
+    }
+
+    /// Gets the direction that the given character maps to, if any.
+    pub fn get(&self, c: char) -> Option<D> {
+        self.map.get(&c).copied()
+    }
+
+    /// Converts a single character into a direction.
+    pub fn parse(&self, c: char) -> Result<D, DirFromCharError> {
+        self.get(c).ok_or(DirFromCharError)
+    }
+
+    /// Returns an iterator which parses every character in the given string into a direction. Whitespace is skipped.
+    pub fn parse_iter<'a>(&'a self, s: &'a str) -> impl Iterator<Item = Result<D, ParseDirError>> + 'a {
+        s.chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| self.get(c).ok_or(ParseDirError::InvalidChar(c)))
+    }
+
+    /// Parses every character in the given string into a direction. Whitespace is skipped.
+    pub fn parse_str(&self, s: &str) -> Result<Vec<D>, ParseDirError> {
+        self.parse_iter(s).collect()
     }
 }
 
+impl DirectionParser<Dir4> {
+    /// Creates a parser for the letters `U`, `D`, `L`, and `R`.
+    pub fn udlr() -> Self {
+        DirectionParser::new()
+            .with('U', Dir4::Up)
+            .with('D', Dir4::Down)
+            .with('L', Dir4::Left)
+            .with('R', Dir4::Right)
+    }
+
+    /// Creates a parser for the compass letters `N`, `E`, `S`, and `W`.
+    pub fn compass() -> Self {
+        DirectionParser::new()
+            .with('N', Dir4::Up)
+            .with('E', Dir4::Right)
+            .with('S', Dir4::Down)
+            .with('W', Dir4::Left)
+    }
+}
+
+impl<D: Direction> FromIterator<(char, D)> for DirectionParser<D> {
+    fn from_iter<T: IntoIterator<Item = (char, D)>>(iter: T) -> Self {
+        DirectionParser { map: iter.into_iter().collect() }
+    }
+}
 
 /// An iterator that yields all four [up–right–down–left directions][Dir4] in clockwise order.
 #[derive(Debug, Clone, Copy)]
@@ -829,3 +1074,237 @@ impl ExactSizeIterator for Dir8Iter {
 }
 
 impl FusedIterator for Dir8Iter {}
+
+/// The outcome of trying to move a [`Walker`] forwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step<Idx: GridIndex> {
+    /// The walker moved forwards into the given position.
+    Moved(Idx),
+    /// The walker could not move forwards because the given position (directly in front of it) is blocked. The walker
+    /// has not moved.
+    Blocked(Idx),
+    /// Moving forwards would have taken the walker outside the bounds of the grid. The walker has not moved.
+    OutOfBounds,
+}
+
+/// An agent that walks around a grid, keeping track of both its position and the direction it's facing.
+///
+/// Many puzzles involve a guard, robot, or some other agent that steps forwards and turns left and right as it goes.
+/// This struct takes care of the bookkeeping for that. All movement is bounds-checked against the size of the grid the
+/// walker was created for, and the walker can optionally record every `(pos, dir)` state it has been in (see
+/// [`Walker::with_history`]). Since those states fully determine the walker's future, recording them makes it easy to
+/// detect when the walker has started going in circles.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::grid::{Dir4, Grid};
+/// # use aoc_utils::grid::directions::{Step, Walker};
+/// let map = Grid::from_lines(["..#", "...", "..."]).unwrap();
+/// let mut guard = Walker::new((1, 2), Dir4::Up, map.size());
+///
+/// assert_eq!(guard.step(|p| map[p] == '#'), Step::Moved((1, 1)));
+/// assert_eq!(guard.step(|p| map[p] == '#'), Step::Moved((1, 0)));
+/// assert_eq!(guard.step(|p| map[p] == '#'), Step::OutOfBounds);
+///
+/// guard.turn_right();
+/// assert_eq!(guard.peek(), Some((2, 0)));
+/// assert_eq!(guard.step(|p| map[p] == '#'), Step::Blocked((2, 0)));
+/// assert_eq!(guard.pos(), (1, 0));
+/// ```
+pub struct Walker<Idx: GridIndex, D: Direction> {
+    pos: Idx,
+    dir: D,
+    limits: (usize, usize),
+    history: Option<Box<dyn History<Idx, D>>>,
+    looped: bool,
+}
+
+/// A record of the states a [`Walker`] has been in.
+///
+/// Recording states needs `Hash + Eq`, which the walker's movement methods otherwise have no use for. Keeping the
+/// history behind this trait means that only [`Walker::with_history`] needs those bounds.
+trait History<Idx, D> {
+    /// Records a state, returning `false` if it had already been recorded before.
+    fn record(&mut self, pos: Idx, dir: D) -> bool;
+
+    /// Gets all of the states recorded so far.
+    fn states(&self) -> &HashSet<(Idx, D)>;
+
+    /// Clones this history into a new box.
+    fn clone_box(&self) -> Box<dyn History<Idx, D>>;
+}
+
+impl<Idx, D> History<Idx, D> for HashSet<(Idx, D)>
+where
+    Idx: Hash + Eq + Clone + 'static,
+    D: Hash + Eq + Clone + 'static,
+{
+    fn record(&mut self, pos: Idx, dir: D) -> bool {
+        self.insert((pos, dir))
+    }
+
+    fn states(&self) -> &HashSet<(Idx, D)> {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn History<Idx, D>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<Idx: GridIndex, D: Direction> Clone for Walker<Idx, D> {
+    fn clone(&self) -> Self {
+        Walker {
+            pos: self.pos,
+            dir: self.dir,
+            limits: self.limits,
+            history: self.history.as_ref().map(|h| h.clone_box()),
+            looped: self.looped,
+        }
+    }
+}
+
+impl<Idx: GridIndex + Debug, D: Direction + Debug> Debug for Walker<Idx, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Walker")
+            .field("pos", &self.pos)
+            .field("dir", &self.dir)
+            .field("limits", &self.limits)
+            .field("history", &self.history.as_ref().map(|h| h.states()))
+            .field("looped", &self.looped)
+            .finish()
+    }
+}
+
+impl<Idx: GridIndex, D: Direction> Walker<Idx, D> {
+    /// Creates a new walker at the given position, facing in the given direction, within a grid of size `(w, h)`.
+    pub fn new(pos: Idx, dir: D, limits: (usize, usize)) -> Self {
+        Walker {
+            pos,
+            dir,
+            limits,
+            history: None,
+            looped: false,
+        }
+    }
+
+    /// Returns the walker's current position.
+    pub const fn pos(&self) -> Idx {
+        self.pos
+    }
+
+    /// Returns the direction the walker is currently facing.
+    pub const fn dir(&self) -> D {
+        self.dir
+    }
+
+    /// Returns the walker's current position and direction together.
+    pub const fn state(&self) -> (Idx, D) {
+        (self.pos, self.dir)
+    }
+
+    /// Returns the position directly in front of the walker, or `None` if that position would be out of bounds.
+    pub fn peek(&self) -> Option<Idx> {
+        self.dir.checked_add(self.pos, self.limits)
+    }
+
+    /// Returns the position `n` steps in front of the walker, or `None` if that position would be out of bounds.
+    pub fn peek_n(&self, n: usize) -> Option<Idx> {
+        self.dir.checked_add_n(self.pos, n, self.limits)
+    }
+
+    /// Points the walker in a new direction.
+    pub fn set_dir(&mut self, dir: D) {
+        self.dir = dir;
+        self.record();
+    }
+
+    /// Turns the walker 90° to the right.
+    pub fn turn_right(&mut self) {
+        self.set_dir(self.dir.right90());
+    }
+
+    /// Turns the walker 90° to the left.
+    pub fn turn_left(&mut self) {
+        self.set_dir(self.dir.left90());
+    }
+
+    /// Turns the walker 180° to face the other way.
+    pub fn turn_around(&mut self) {
+        self.set_dir(self.dir.behind());
+    }
+
+    /// Attempts to move the walker one step forwards.
+    ///
+    /// `is_blocked` is called with the position in front of the walker (as long as it's in bounds) to check if the
+    /// walker is allowed to move there.
+    pub fn step(&mut self, is_blocked: impl FnOnce(Idx) -> bool) -> Step<Idx> {
+        match self.peek() {
+            None => Step::OutOfBounds,
+            Some(next) if is_blocked(next) => Step::Blocked(next),
+            Some(next) => {
+                self.pos = next;
+                self.record();
+                Step::Moved(next)
+            },
+        }
+    }
+
+    /// Attempts to move the walker up to `n` steps forwards, stopping early if it becomes blocked or would leave the
+    /// grid.
+    ///
+    /// Returns the outcome of the last step that was attempted. If `n` is zero, the walker does not move and
+    /// [`Step::Moved`] is returned with its current position.
+    pub fn step_n(&mut self, n: usize, mut is_blocked: impl FnMut(Idx) -> bool) -> Step<Idx> {
+        let mut res = Step::Moved(self.pos);
+        for _ in 0..n {
+            res = self.step(&mut is_blocked);
+            if !matches!(res, Step::Moved(_)) {
+                break;
+            }
+        }
+        res
+    }
+
+    fn record(&mut self) {
+        if let Some(history) = &mut self.history
+            && !history.record(self.pos, self.dir)
+        {
+            self.looped = true;
+        }
+    }
+}
+
+impl<Idx, D> Walker<Idx, D>
+where
+    Idx: GridIndex + Hash + Eq + 'static,
+    D: Direction + Hash + Eq + 'static,
+{
+    /// Enables recording of every `(pos, dir)` state this walker ends up in, starting with the current one.
+    pub fn with_history(mut self) -> Self {
+        self.history = Some(Box::new(HashSet::from([self.state()])));
+        self
+    }
+
+    /// Returns `true` if the walker has, at some point, been at the given position while facing the given direction.
+    ///
+    /// Always returns `false` if history is not being [recorded][Walker::with_history].
+    pub fn has_visited(&self, pos: Idx, dir: D) -> bool {
+        self.history.as_ref().is_some_and(|h| h.states().contains(&(pos, dir)))
+    }
+
+    /// Returns the set of all `(pos, dir)` states this walker has been in, if history is being
+    /// [recorded][Walker::with_history].
+    pub fn history(&self) -> Option<&HashSet<(Idx, D)>> {
+        self.history.as_ref().map(|h| h.states())
+    }
+
+    /// Returns `true` if the walker has ever re-entered a state that it had already been in before, meaning that
+    /// (assuming the world around it hasn't changed) it's now stuck in a loop.
+    ///
+    /// Always returns `false` if history is not being [recorded][Walker::with_history].
+    pub const fn is_looping(&self) -> bool {
+        self.looped
+    }
+}
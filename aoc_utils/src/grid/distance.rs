@@ -0,0 +1,214 @@
+//! Distance metrics and iterators over the cells within some distance of a position.
+//!
+//! [`Neighbours`][super::Neighbours] only covers the cells immediately around a given cell. The iterators in this
+//! module cover larger areas: every cell within some radius (see [`Grid::within`][super::Grid::within]) or every cell
+//! at _exactly_ some distance (see [`Grid::ring`][super::Grid::ring]).
+
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use super::GridIndex;
+
+/// A way of measuring the distance between two positions in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// The "taxicab" distance: `|dx| + |dy|`. Cells within some Manhattan distance form a diamond.
+    Manhattan,
+    /// The "chessboard" distance: `max(|dx|, |dy|)`. Cells within some Chebyshev distance form a square.
+    Chebyshev,
+    /// The straight-line distance: `sqrt(dx² + dy²)`. Cells within some Euclidean distance form a (pixelated) circle.
+    Euclidean,
+}
+
+impl Metric {
+    /// Computes the distance between two positions.
+    ///
+    /// [Euclidean][Metric::Euclidean] distances are rounded up to the nearest integer. That way, `a` is within a radius
+    /// `r` of `b` if and only if `distance(a, b) <= r`, regardless of metric.
+    pub fn distance<Idx: GridIndex>(self, a: Idx, b: Idx) -> usize {
+        let dx = a.x().abs_diff(b.x());
+        let dy = a.y().abs_diff(b.y());
+        match self {
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+            Metric::Euclidean => {
+                let sq = dx.checked_mul(dx).and_then(|x2| dy.checked_mul(dy).and_then(|y2| x2.checked_add(y2)));
+                match sq {
+                    Some(sq) => {
+                        let root = sq.isqrt();
+                        if root * root < sq { root + 1 } else { root }
+                    },
+                    None => {
+                        // Far enough apart that squaring overflows, so fall back to doing the math in 128 bits.
+                        let sq = (dx as u128).pow(2) + (dy as u128).pow(2);
+                        let root = sq.isqrt();
+                        let root = if root * root < sq { root + 1 } else { root };
+                        usize::try_from(root).unwrap_or(usize::MAX)
+                    },
+                }
+            },
+        }
+    }
+
+    /// Determines how far a row of cells that is `dy` rows away from the centre extends to either side while staying
+    /// within `radius`. Assumes `dy <= radius`.
+    fn half_width(self, radius: usize, dy: usize) -> usize {
+        match self {
+            Metric::Manhattan => radius - dy,
+            Metric::Chebyshev => radius,
+            Metric::Euclidean => match radius.checked_mul(radius) {
+                Some(r2) => (r2 - dy * dy).isqrt(),
+                // The result is at most `radius`, so only the intermediate values need the extra bits.
+                None => ((radius as u128).pow(2) - (dy as u128).pow(2)).isqrt() as usize,
+            },
+        }
+    }
+}
+
+/// An iterator over all positions within some distance of a central position, clipped to the bounds of a grid.
+///
+/// This struct is created by the [`within`][super::Grid::within] method on [`Grid`][super::Grid]. Positions are
+/// yielded in row-major order (left-to-right, top-to-bottom).
+#[derive(Debug, Clone)]
+pub struct Within<Idx: GridIndex> {
+    centre: (usize, usize),
+    radius: usize,
+    metric: Metric,
+    width: usize,
+    /// The current row.
+    y: usize,
+    /// One past the last row.
+    y_end: usize,
+    /// The next position on the current row.
+    x: usize,
+    /// One past the last position on the current row.
+    x_end: usize,
+    _marker: PhantomData<Idx>,
+}
+
+impl<Idx: GridIndex> Within<Idx> {
+    /// Creates a new iterator over all positions within `radius` of `pos` in a grid with the given `(w, h)` size.
+    pub fn new(pos: Idx, radius: usize, metric: Metric, (w, h): (usize, usize)) -> Self {
+        let (cx, cy) = pos.to_tuple();
+        let mut iter = Within {
+            centre: (cx, cy),
+            radius,
+            metric,
+            width: w,
+            y: cy.saturating_sub(radius),
+            y_end: cy.saturating_add(radius).saturating_add(1).min(h),
+            x: 0,
+            x_end: 0,
+            _marker: PhantomData,
+        };
+
+        iter.start_row();
+        iter
+    }
+
+    /// Sets `x` and `x_end` for the current row.
+    fn start_row(&mut self) {
+        let (cx, cy) = self.centre;
+        let dy = self.y.abs_diff(cy);
+        if self.y < self.y_end && dy <= self.radius {
+            let hw = self.metric.half_width(self.radius, dy);
+            self.x = cx.saturating_sub(hw);
+            self.x_end = cx.saturating_add(hw).saturating_add(1).min(self.width);
+        } else {
+            self.x = 0;
+            self.x_end = 0;
+        }
+    }
+}
+
+impl<Idx: GridIndex> Iterator for Within<Idx> {
+    type Item = Idx;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.x >= self.x_end {
+            if self.y >= self.y_end {
+                return None;
+            }
+
+            self.y += 1;
+            self.start_row();
+        }
+
+        let pos = Idx::from_xy(self.x, self.y);
+        self.x += 1;
+        Some(pos)
+    }
+}
+
+impl<Idx: GridIndex> FusedIterator for Within<Idx> {}
+
+/// An iterator over all positions at exactly some Manhattan distance from a central position, clipped to the bounds of
+/// a grid.
+///
+/// This struct is created by the [`ring`][super::Grid::ring] method on [`Grid`][super::Grid]. Positions are yielded
+/// from top to bottom, left side before right side.
+#[derive(Debug, Clone)]
+pub struct Ring<Idx: GridIndex> {
+    centre: (usize, usize),
+    k: usize,
+    limits: (usize, usize),
+    /// The next row to visit, as an offset of `0..=2k` from the top of the diamond.
+    row: usize,
+    /// Whether or not the left side of the current row has already been visited.
+    left_done: bool,
+    _marker: PhantomData<Idx>,
+}
+
+impl<Idx: GridIndex> Ring<Idx> {
+    /// Creates a new iterator over all positions at a Manhattan distance of exactly `k` from `pos` in a grid with the
+    /// given `(w, h)` size.
+    pub fn new(pos: Idx, k: usize, limits: (usize, usize)) -> Self {
+        Ring {
+            centre: pos.to_tuple(),
+            k,
+            limits,
+            row: 0,
+            left_done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Computes the position for the current row and side, if it exists.
+    fn candidate(&self) -> Option<(usize, usize)> {
+        let (cx, cy) = self.centre;
+        let (w, h) = self.limits;
+
+        // Row `k` is the centre row.
+        let y = (cy + self.row).checked_sub(self.k)?;
+        let dx = self.k - self.row.abs_diff(self.k);
+        let x = if self.left_done { cx.checked_add(dx)? } else { cx.checked_sub(dx)? };
+        (x < w && y < h).then_some((x, y))
+    }
+}
+
+impl<Idx: GridIndex> Iterator for Ring<Idx> {
+    type Item = Idx;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.row <= 2 * self.k {
+            let pos = self.candidate();
+
+            // The top and bottom points of the diamond only have one "side".
+            let single = self.row == 0 || self.row == 2 * self.k;
+            if self.left_done || single {
+                self.row += 1;
+                self.left_done = false;
+            } else {
+                self.left_done = true;
+            }
+
+            if let Some((x, y)) = pos {
+                return Some(Idx::from_xy(x, y));
+            }
+        }
+
+        None
+    }
+}
+
+impl<Idx: GridIndex> FusedIterator for Ring<Idx> {}
@@ -7,9 +7,12 @@
 // [TODO] Add support for signed indexes to make it easier to handle falling out of bounds in a grid.
 // [TODO] Add proper tests.
 
+pub mod automaton;
 pub mod directions;
+pub mod distance;
 pub mod iter;
 pub mod neighbours;
+pub mod turns;
 
 use std::convert::Infallible;
 use std::fmt::{self, Debug, Write};
@@ -17,9 +20,13 @@ use std::ops::{Index, IndexMut};
 
 use thiserror::Error;
 
+pub use self::automaton::{Automaton, Neighbourhood};
 pub use self::directions::{Dir4, Dir8, Direction};
+pub use self::distance::Metric;
+use self::distance::{Ring, Within};
 use self::iter::{Entries, EntriesMut, Positions, Values, ValuesMut};
 pub use self::neighbours::Neighbours;
+pub use self::turns::{Move, Turn};
 
 /// A 2D position used to index a [Grid].
 pub type Pos = (usize, usize);
@@ -254,6 +261,34 @@ impl<T> Grid<T> {
         self.contains(pos).then(|| Neighbours::new(pos, self.size()))
     }
 
+    /// Returns an iterator over all positions within `radius` of the given position (including the position itself)
+    /// according to the given [distance metric][Metric]. Any positions outside the bounds of this grid are excluded.
+    ///
+    /// ```
+    /// # use aoc_utils::grid::{Grid, Metric};
+    /// let grid = Grid::<u8>::empty(10, 10);
+    /// assert_eq!(grid.within((5, 5), 2, Metric::Manhattan).count(), 13);
+    /// assert_eq!(grid.within((5, 5), 2, Metric::Chebyshev).count(), 25);
+    /// assert_eq!(grid.within((0, 0), 2, Metric::Chebyshev).count(), 9);
+    /// ```
+    pub fn within<Idx: GridIndex>(&self, pos: Idx, radius: usize, metric: Metric) -> Within<Idx> {
+        Within::new(pos, radius, metric, self.size())
+    }
+
+    /// Returns an iterator over all positions at a [Manhattan distance][Metric::Manhattan] of exactly `k` from the
+    /// given position (i.e., a diamond-shaped ring around it). Any positions outside the bounds of this grid are
+    /// excluded.
+    ///
+    /// ```
+    /// # use aoc_utils::grid::Grid;
+    /// let grid = Grid::<u8>::empty(10, 10);
+    /// assert_eq!(grid.ring((5, 5), 2).count(), 8);
+    /// assert_eq!(grid.ring((0, 5), 2).collect::<Vec<_>>(), [(0, 3), (1, 4), (2, 5), (1, 6), (0, 7)]);
+    /// ```
+    pub fn ring<Idx: GridIndex>(&self, pos: Idx, k: usize) -> Ring<Idx> {
+        Ring::new(pos, k, self.size())
+    }
+
     /// Creates a new grid of the given size by calling `f` once for every (x, y) position of the grid.
     pub fn from_fn<F>(w: usize, h: usize, mut f: F) -> Self
     where
@@ -0,0 +1,372 @@
+//! Relative turns and movement instructions.
+//!
+//! [`Dir4`] and [`Dir8`] represent _absolute_ directions. Plenty of puzzles instead give instructions _relative_ to
+//! whichever way something is currently facing: "turn left", "rotate right 90°", "go forward 10". [`Turn`] and [`Move`]
+//! represent those instructions, and can be parsed straight out of puzzle input.
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::grid::Dir4;
+//! use aoc_utils::grid::turns::{Move, Turn};
+//!
+//! let turn = "R90".parse::<Turn>().unwrap();
+//! assert_eq!(Dir4::Up + turn, Dir4::Right);
+//! assert_eq!(Dir4::Up - turn, Dir4::Left);
+//! assert!("L-90".parse::<Turn>().is_err());
+//!
+//! // Turns are reduced to a single rotation, and print in the same format they're parsed from.
+//! let turn = "L450".parse::<Turn>().unwrap();
+//! assert_eq!(turn, Turn::LEFT);
+//! assert_eq!(turn + Turn::AROUND, Turn::RIGHT);
+//! for turn in [Turn::NONE, Turn::LEFT, Turn::AROUND, Turn::new(-135)] {
+//!     assert_eq!(turn.to_string().parse::<Turn>(), Ok(turn));
+//! }
+//!
+//! let moves = Move::parse_all("F10 L ^^>2").unwrap();
+//! assert_eq!(moves, [
+//!     Move::Forward(10),
+//!     Move::Turn(Turn::LEFT),
+//!     Move::Go(Dir4::Up, 1),
+//!     Move::Go(Dir4::Up, 1),
+//!     Move::Go(Dir4::Right, 2),
+//! ]);
+//! ```
+
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::ops::Neg;
+use std::str::FromStr;
+
+use auto_ops::impl_op_ex;
+use thiserror::Error;
+
+use super::{Dir4, Dir8};
+
+/// A rotation relative to the current direction.
+///
+/// Turns are measured in degrees, with positive values turning clockwise (to the right) and negative values turning
+/// counter-clockwise (to the left). Adding a turn to a [`Dir4`] requires that it be a multiple of 90°; adding one to a
+/// [`Dir8`] requires a multiple of 45°.
+///
+/// Since turning all the way around brings you back to where you started, turns are always reduced to a single
+/// rotation between -180° (exclusive) and 180° (inclusive). For example, turning 270° to the left is the same as
+/// turning 90° to the right. This also means that adding turns together can never overflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Turn(i32);
+
+impl Turn {
+    /// No rotation at all.
+    pub const NONE: Turn = Turn(0);
+
+    /// A 90° turn to the left (counter-clockwise).
+    pub const LEFT: Turn = Turn(-90);
+
+    /// A 90° turn to the right (clockwise).
+    pub const RIGHT: Turn = Turn(90);
+
+    /// A 180° turn.
+    pub const AROUND: Turn = Turn(180);
+
+    /// Creates a new turn of the given (signed) number of degrees. Positive values represent clockwise rotations.
+    pub const fn new(degrees: i32) -> Self {
+        Turn(normalize(degrees as i64))
+    }
+
+    /// Creates a new turn of the given number of degrees to the left.
+    pub const fn left(degrees: u32) -> Self {
+        Turn(normalize(-(degrees as i64)))
+    }
+
+    /// Creates a new turn of the given number of degrees to the right.
+    pub const fn right(degrees: u32) -> Self {
+        Turn(normalize(degrees as i64))
+    }
+
+    /// Gets the (signed) number of degrees this turn rotates by, between -180 (exclusive) and 180 (inclusive). Positive
+    /// values represent clockwise rotations.
+    pub const fn degrees(self) -> i32 {
+        self.0
+    }
+
+    /// Returns `true` if this turn is to the left (counter-clockwise).
+    pub const fn is_left(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns `true` if this turn is to the right (clockwise).
+    pub const fn is_right(self) -> bool {
+        self.0 > 0
+    }
+
+    /// Gets the (signed) number of 90° steps this turn represents, if it is a multiple of 90°.
+    pub const fn steps90(self) -> Option<i32> {
+        if self.0 % 90 == 0 { Some(self.0 / 90) } else { None }
+    }
+
+    /// Gets the (signed) number of 45° steps this turn represents, if it is a multiple of 45°.
+    pub const fn steps45(self) -> Option<i32> {
+        if self.0 % 45 == 0 { Some(self.0 / 45) } else { None }
+    }
+}
+
+/// Reduces a number of degrees to a single rotation, between -180 (exclusive) and 180 (inclusive).
+const fn normalize(degrees: i64) -> i32 {
+    let d = degrees.rem_euclid(360) as i32;
+    if d > 180 { d - 360 } else { d }
+}
+
+impl Neg for Turn {
+    type Output = Turn;
+    fn neg(self) -> Self::Output {
+        Turn(normalize(-(self.0 as i64)))
+    }
+}
+
+impl Display for Turn {
+    /// Formats this turn as an `L` or `R` followed by a number of degrees, the same way it is parsed. A turn of zero
+    /// degrees is written as `R0`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0 < 0 {
+            write!(f, "L{}", self.0.unsigned_abs())
+        } else {
+            write!(f, "R{}", self.0)
+        }
+    }
+}
+
+impl_op_ex!(+ |a: &Turn, b: &Turn| -> Turn { Turn(normalize(a.0 as i64 + b.0 as i64)) });
+impl_op_ex!(- |a: &Turn, b: &Turn| -> Turn { Turn(normalize(a.0 as i64 - b.0 as i64)) });
+
+impl Dir4 {
+    /// Rotates this direction by the given turn, returning `None` if the turn is not a multiple of 90°.
+    pub const fn checked_turn(self, turn: Turn) -> Option<Self> {
+        match turn.steps90() {
+            Some(n) => Some(self.right_n(n)),
+            None => None,
+        }
+    }
+
+    /// Rotates this direction by the given turn.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the turn is not a multiple of 90°.
+    pub const fn turn(self, turn: Turn) -> Self {
+        match self.checked_turn(turn) {
+            Some(dir) => dir,
+            None => panic!("Dir4 can only be turned by multiples of 90°"),
+        }
+    }
+}
+
+impl Dir8 {
+    /// Rotates this direction by the given turn, returning `None` if the turn is not a multiple of 45°.
+    pub const fn checked_turn(self, turn: Turn) -> Option<Self> {
+        match turn.steps45() {
+            Some(n) => Some(self.right_n(n)),
+            None => None,
+        }
+    }
+
+    /// Rotates this direction by the given turn.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the turn is not a multiple of 45°.
+    pub const fn turn(self, turn: Turn) -> Self {
+        match self.checked_turn(turn) {
+            Some(dir) => dir,
+            None => panic!("Dir8 can only be turned by multiples of 45°"),
+        }
+    }
+}
+
+macro_rules! impl_turn_ops {
+    ($dir:ty) => {
+        impl_op_ex!(+ |dir: &$dir, turn: &Turn| -> $dir { dir.turn(*turn) });
+        impl_op_ex!(- |dir: &$dir, turn: &Turn| -> $dir { dir.turn(-*turn) });
+        impl_op_ex!(+= |dir: &mut $dir, turn: &Turn| { *dir = dir.turn(*turn); });
+        impl_op_ex!(-= |dir: &mut $dir, turn: &Turn| { *dir = dir.turn(-*turn); });
+    };
+}
+
+impl_turn_ops!(Dir4);
+impl_turn_ops!(Dir8);
+
+/// A single movement instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Move {
+    /// Rotate in place.
+    Turn(Turn),
+    /// Move forwards the given number of steps in whichever direction is currently being faced.
+    Forward(usize),
+    /// Move the given number of steps in an absolute direction, regardless of which direction is currently being
+    /// faced.
+    Go(Dir4, usize),
+}
+
+/// An error representing failure to convert a single `char` to a [`Turn`] or [`Move`].
+#[derive(Debug, PartialEq, Eq, Error)]
+#[error("character is not a valid turn or move")]
+pub struct TurnFromCharError;
+
+/// An error representing failure to parse a string into a [`Turn`] or [`Move`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ParseTurnError {
+    /// The string was empty.
+    #[error("string must not be empty")]
+    Empty,
+
+    /// The instruction character does not represent a turn or move.
+    #[error("character '{}' (U+{:04X}) is not a valid instruction", .0, *(.0) as u32)]
+    InvalidChar(char),
+
+    /// The amount following the instruction character could not be parsed.
+    #[error("invalid instruction amount: {0}")]
+    InvalidAmount(#[from] ParseIntError),
+
+    /// The amount following the instruction character had a sign. Amounts must be unsigned; the direction of a turn
+    /// comes from its instruction character.
+    #[error("instruction amount must not have a sign")]
+    SignedAmount,
+}
+
+impl TryFrom<char> for Turn {
+    type Error = TurnFromCharError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' | 'l' | '↺' | '↶' => Ok(Turn::LEFT),
+            'R' | 'r' | '↻' | '↷' => Ok(Turn::RIGHT),
+            _ => Err(TurnFromCharError),
+        }
+    }
+}
+
+/// Parses the amount following an instruction character, rejecting any leading sign (which [`str::parse`] would
+/// otherwise accept).
+fn parse_amount<T: FromStr<Err = ParseIntError>>(s: &str) -> Result<T, ParseTurnError> {
+    if s.starts_with(['+', '-']) {
+        Err(ParseTurnError::SignedAmount)
+    } else {
+        Ok(s.parse()?)
+    }
+}
+
+/// Splits an instruction into its leading character and the (possibly empty) remainder of the string.
+fn split_instruction(s: &str) -> Result<(char, &str), ParseTurnError> {
+    let c = s.chars().next().ok_or(ParseTurnError::Empty)?;
+    Ok((c, &s[c.len_utf8()..]))
+}
+
+impl FromStr for Turn {
+    type Err = ParseTurnError;
+
+    /// Parses a turn from either a single character (`L`, `R`, `↺`, `↻`) or from an `L` or `R` followed by a number of
+    /// degrees (`L90`, `R180`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (c, rest) = split_instruction(s)?;
+        let turn = Turn::try_from(c).or(Err(ParseTurnError::InvalidChar(c)))?;
+        if rest.is_empty() {
+            Ok(turn)
+        } else {
+            let amount = parse_amount::<u32>(rest)?;
+            Ok(if turn.is_left() { Turn::left(amount) } else { Turn::right(amount) })
+        }
+    }
+}
+
+/// Parses a left or right rotation by some amount, in whatever units the puzzle uses, into a signed number: negative for
+/// left (counter-clockwise) and positive for right (clockwise).
+///
+/// The instruction is written the same way as a [`Turn`] with an amount (`L68`, `R14`), but the amount isn't treated as
+/// degrees or reduced to a single rotation. That makes this suitable for things like the number of clicks to turn a
+/// dial.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::grid::turns::parse_rotation;
+/// assert_eq!(parse_rotation::<i64>("L68"), Ok(-68));
+/// assert_eq!(parse_rotation::<i64>("R1000"), Ok(1000));
+/// assert!(parse_rotation::<i64>("R").is_err());
+/// ```
+pub fn parse_rotation<T>(s: &str) -> Result<T, ParseTurnError>
+where
+    T: FromStr<Err = ParseIntError> + Neg<Output = T>,
+{
+    let (c, rest) = split_instruction(s)?;
+    let turn = Turn::try_from(c).or(Err(ParseTurnError::InvalidChar(c)))?;
+    let amount = parse_amount::<T>(rest)?;
+    Ok(if turn.is_left() { -amount } else { amount })
+}
+
+impl TryFrom<char> for Move {
+    type Error = TurnFromCharError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if let Ok(turn) = Turn::try_from(value) {
+            Ok(Move::Turn(turn))
+        } else if let Ok(dir) = Dir4::try_from(value) {
+            Ok(Move::Go(dir, 1))
+        } else if matches!(value, 'F' | 'f') {
+            Ok(Move::Forward(1))
+        } else {
+            Err(TurnFromCharError)
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseTurnError;
+
+    /// Parses a single movement instruction:
+    ///
+    /// - turns, in any format accepted by [`Turn`]'s [`FromStr`] implementation (`L`, `R90`);
+    /// - forward movement, as `F` followed by an optional number of steps (`F`, `F10`), or as a bare number (`10`);
+    /// - absolute movement, as any character accepted by [`Dir4`]'s [`TryFrom<char>`] implementation followed by an
+    ///   optional number of steps (`^`, `>3`, `↓`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (c, rest) = split_instruction(s)?;
+        if c.is_ascii_digit() {
+            return Ok(Move::Forward(s.parse()?));
+        }
+
+        match Move::try_from(c).or(Err(ParseTurnError::InvalidChar(c)))? {
+            Move::Turn(_) => Ok(Move::Turn(s.parse()?)),
+            Move::Forward(_) if rest.is_empty() => Ok(Move::Forward(1)),
+            Move::Forward(_) => Ok(Move::Forward(parse_amount(rest)?)),
+            Move::Go(dir, _) if rest.is_empty() => Ok(Move::Go(dir, 1)),
+            Move::Go(dir, _) => Ok(Move::Go(dir, parse_amount(rest)?)),
+        }
+    }
+}
+
+impl Move {
+    /// Parses a whole sequence of movement instructions.
+    ///
+    /// Instructions may be separated by whitespace or commas, or may be given with no separators at all (`R90F3L90`,
+    /// `^^v<>`, `10R90F5`). Each instruction is parsed according to [`Move`]'s [`FromStr`] implementation.
+    pub fn parse_all(s: &str) -> Result<Vec<Move>, ParseTurnError> {
+        let mut moves = Vec::new();
+        let mut rest = s;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+
+            // Each instruction is either a run of digits or a single character followed by a run of digits.
+            let start = if c.is_ascii_digit() { 0 } else { c.len_utf8() };
+            let end = rest[start..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(rest.len(), |i| start + i);
+
+            moves.push(rest[..end].parse()?);
+            rest = &rest[end..];
+        }
+
+        Ok(moves)
+    }
+}
@@ -0,0 +1,320 @@
+//! A k-d tree for nearest-neighbour queries on points in any number of dimensions.
+//!
+//! Puzzles that ask for the closest point to something (or the closest _pairs_ of points) can usually get away with
+//! checking every possibility, but that gets slow once there are a few thousand points. A [`KdTree`] splits the points
+//! up into nested boxes so that most of them can be ruled out without ever being looked at.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::cuboid::Cuboid;
+use crate::math::PrimInt;
+
+/// A static k-d tree over a list of points in `N` dimensions.
+///
+/// Points are stored as `[T; N]` arrays, but anything that converts into one can be passed in (e.g.,
+/// [`Vec2`][crate::vec::Vec2], [`Vec3`][crate::vec::Vec3], or [`Point`][crate::geom::Point]). All queries return points
+/// by their index in the original list, alongside their squared Euclidean distance from the query point. Ties between
+/// points at the same distance are broken by index.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::kdtree::KdTree;
+/// let tree = KdTree::new([[0, 0], [5, 5], [1, 2], [9, 0], [4, 4]]);
+///
+/// assert_eq!(tree.nearest([4, 3]), Some((4, 1)));
+/// assert_eq!(tree.k_nearest([0, 1], 2), [(0, 1), (2, 2)]);
+/// assert_eq!(tree.within([5, 5], 2), [1, 4]);
+///
+/// // Pairs of points, closest first:
+/// let pairs = tree.closest_pairs().take(3).collect::<Vec<_>>();
+/// assert_eq!(pairs, [(1, 4, 2), (0, 2, 5), (2, 4, 13)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<const N: usize, T = i64> {
+    points: Vec<[T; N]>,
+    /// The tree, stored implicitly: the subtree over `nodes[lo..hi]` has its root at `(lo + hi) / 2`, with its left and
+    /// right subtrees on either side of it.
+    nodes: Vec<Node<N, T>>,
+}
+
+#[derive(Debug, Clone)]
+struct Node<const N: usize, T> {
+    /// The index of the point at this node.
+    point: usize,
+    /// The axis that this node splits its subtree along.
+    axis: usize,
+    /// The bounding box of every point in this node's subtree.
+    bounds: Cuboid<N, T>,
+}
+
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// The squared Euclidean distance between two points.
+fn dist_sq<const N: usize, T: PrimInt>(a: &[T; N], b: &[T; N]) -> T {
+    (0..N).fold(T::ZERO, |acc, i| {
+        let d = abs_diff(a[i], b[i]);
+        acc + d * d
+    })
+}
+
+/// The squared Euclidean distance from a point to the closest point in a box.
+fn box_dist_sq<const N: usize, T: PrimInt>(p: &[T; N], bounds: &Cuboid<N, T>) -> T {
+    let (min, max) = (bounds.min(), bounds.max());
+    (0..N).fold(T::ZERO, |acc, i| {
+        let d = match p[i] {
+            x if x < min[i] => min[i] - x,
+            x if x > max[i] => x - max[i],
+            _ => T::ZERO,
+        };
+        acc + d * d
+    })
+}
+
+impl<const N: usize, T: PrimInt> KdTree<N, T> {
+    /// Builds a new k-d tree from a list of points.
+    pub fn new(points: impl IntoIterator<Item = impl Into<[T; N]>>) -> Self {
+        let points = points.into_iter().map(Into::into).collect::<Vec<_>>();
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        let mut nodes = Vec::with_capacity(points.len());
+
+        if !points.is_empty() {
+            let placeholder = Node { point: 0, axis: 0, bounds: Cuboid::point(points[0]) };
+            nodes.resize(points.len(), placeholder);
+            Self::build(&points, &mut order, &mut nodes);
+        }
+
+        KdTree { points, nodes }
+    }
+
+    /// Recursively builds the subtree for the given slice of point indices, writing it into the matching slice of nodes.
+    fn build(points: &[[T; N]], order: &mut [usize], nodes: &mut [Node<N, T>]) {
+        if order.is_empty() {
+            return;
+        }
+
+        let mut min = points[order[0]];
+        let mut max = points[order[0]];
+        for p in order.iter().map(|&i| &points[i]) {
+            for a in 0..N {
+                min[a] = min[a].min(p[a]);
+                max[a] = max[a].max(p[a]);
+            }
+        }
+
+        // Split along whichever axis the points are most spread out on.
+        let axis = (0..N).max_by_key(|&a| max[a] - min[a]).unwrap_or(0);
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+
+        nodes[mid] = Node {
+            point: order[mid],
+            axis,
+            bounds: Cuboid::new(min, max),
+        };
+
+        let (left_order, right_order) = order.split_at_mut(mid);
+        let (left_nodes, right_nodes) = nodes.split_at_mut(mid);
+        Self::build(points, left_order, left_nodes);
+        Self::build(points, &mut right_order[1..], &mut right_nodes[1..]);
+    }
+
+    /// The points in this tree, in their original order.
+    pub fn points(&self) -> &[[T; N]] {
+        &self.points
+    }
+
+    /// The number of points in this tree.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Checks whether this tree has no points in it.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Finds the point closest to `target`. Returns its index and squared distance.
+    pub fn nearest(&self, target: impl Into<[T; N]>) -> Option<(usize, T)> {
+        self.k_nearest(target, 1).pop()
+    }
+
+    /// Finds the `k` points closest to `target`, closest first. Returns their indices and squared distances.
+    ///
+    /// If there are fewer than `k` points in the tree, all of them are returned.
+    pub fn k_nearest(&self, target: impl Into<[T; N]>, k: usize) -> Vec<(usize, T)> {
+        let target = target.into();
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.k_nearest_in(0, self.nodes.len(), &target, k, &mut best);
+        }
+
+        // `into_sorted_vec` sorts in ascending order, which is exactly what we want.
+        best.into_sorted_vec().into_iter().map(|(d, i)| (i, d)).collect()
+    }
+
+    fn k_nearest_in(&self, lo: usize, hi: usize, target: &[T; N], k: usize, best: &mut BinaryHeap<(T, usize)>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        let node = &self.nodes[mid];
+        if best.len() == k && box_dist_sq(target, &node.bounds) > best.peek().unwrap().0 {
+            return;
+        }
+
+        let point = &self.points[node.point];
+        best.push((dist_sq(target, point), node.point));
+        if best.len() > k {
+            best.pop();
+        }
+
+        // Search whichever side the target is on first, since that's where the closest points probably are. That'll
+        // give us a better chance of skipping the other side entirely.
+        if target[node.axis] < point[node.axis] {
+            self.k_nearest_in(lo, mid, target, k, best);
+            self.k_nearest_in(mid + 1, hi, target, k, best);
+        } else {
+            self.k_nearest_in(mid + 1, hi, target, k, best);
+            self.k_nearest_in(lo, mid, target, k, best);
+        }
+    }
+
+    /// Finds all points within a squared distance of `max_dist_sq` from `target` (inclusive). Returns their indices in
+    /// ascending order.
+    pub fn within(&self, target: impl Into<[T; N]>, max_dist_sq: T) -> Vec<usize> {
+        let target = target.into();
+        let mut found = Vec::new();
+        self.within_in(0, self.nodes.len(), &target, max_dist_sq, &mut found);
+        found.sort_unstable();
+        found
+    }
+
+    fn within_in(&self, lo: usize, hi: usize, target: &[T; N], max_dist_sq: T, found: &mut Vec<usize>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        let node = &self.nodes[mid];
+        if box_dist_sq(target, &node.bounds) > max_dist_sq {
+            return;
+        }
+
+        if dist_sq(target, &self.points[node.point]) <= max_dist_sq {
+            found.push(node.point);
+        }
+
+        self.within_in(lo, mid, target, max_dist_sq, found);
+        self.within_in(mid + 1, hi, target, max_dist_sq, found);
+    }
+
+    /// Returns an iterator over every point in the tree, ordered by their distance from `target`.
+    ///
+    /// Unlike [`k_nearest`][Self::k_nearest], this doesn't need to know how many points are wanted ahead of time: it
+    /// only does as much searching as is needed to find each next point.
+    pub fn neighbours(&self, target: impl Into<[T; N]>) -> Neighbours<'_, N, T> {
+        let mut queue = BinaryHeap::new();
+        if !self.nodes.is_empty() {
+            queue.push(Reverse((T::ZERO, Entry::Node(0, self.nodes.len()))));
+        }
+
+        Neighbours { tree: self, target: target.into(), queue }
+    }
+
+    /// Returns an iterator over every pair of points in the tree, ordered by the distance between them.
+    ///
+    /// Each item is `(i, j, dist_sq)`, with `i < j`. Pairs are found lazily, so taking the closest few pairs is much
+    /// faster than computing and sorting all _n²_ of them.
+    pub fn closest_pairs(&self) -> ClosestPairs<'_, N, T> {
+        let streams = self.points.iter().map(|&p| self.neighbours(p)).collect();
+        let mut pairs = ClosestPairs { streams, queue: BinaryHeap::new() };
+        for i in 0..self.points.len() {
+            pairs.advance(i);
+        }
+        pairs
+    }
+}
+
+impl<const N: usize, T: PrimInt, P: Into<[T; N]>> FromIterator<P> for KdTree<N, T> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        KdTree::new(iter)
+    }
+}
+
+/// An entry in a [`Neighbours`] search queue: either a subtree that hasn't been looked into yet, or a single point.
+///
+/// Subtrees sort before points, so that when a point and a subtree's bounding box are the same distance away, the
+/// subtree gets expanded first. That way, points at the same distance always come out in order of their index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Entry {
+    Node(usize, usize),
+    Point(usize),
+}
+
+/// An iterator over the points in a [`KdTree`], ordered by distance. See [`KdTree::neighbours`] for details.
+#[derive(Debug, Clone)]
+pub struct Neighbours<'a, const N: usize, T> {
+    tree: &'a KdTree<N, T>,
+    target: [T; N],
+    queue: BinaryHeap<Reverse<(T, Entry)>>,
+}
+
+impl<'a, const N: usize, T: PrimInt> Iterator for Neighbours<'a, N, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((dist, entry)) = self.queue.pop()?;
+            let (lo, hi) = match entry {
+                Entry::Point(i) => return Some((i, dist)),
+                Entry::Node(lo, hi) => (lo, hi),
+            };
+
+            let mid = (lo + hi) / 2;
+            let point = self.tree.nodes[mid].point;
+            let point_dist = dist_sq(&self.target, &self.tree.points[point]);
+            self.queue.push(Reverse((point_dist, Entry::Point(point))));
+
+            for (lo, hi) in [(lo, mid), (mid + 1, hi)] {
+                if lo < hi {
+                    let bounds = &self.tree.nodes[(lo + hi) / 2].bounds;
+                    self.queue.push(Reverse((box_dist_sq(&self.target, bounds), Entry::Node(lo, hi))));
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over pairs of points in a [`KdTree`], ordered by distance. See [`KdTree::closest_pairs`] for details.
+#[derive(Debug, Clone)]
+pub struct ClosestPairs<'a, const N: usize, T> {
+    /// For each point `i`, an iterator over its neighbours (some of which will have already been paired up with it).
+    streams: Vec<Neighbours<'a, N, T>>,
+    /// The next closest pair for each point `i`, as `(dist_sq, i, j)`.
+    queue: BinaryHeap<Reverse<(T, usize, usize)>>,
+}
+
+impl<'a, const N: usize, T: PrimInt> ClosestPairs<'a, N, T> {
+    /// Finds the next pair for point `i`.
+    fn advance(&mut self, i: usize) {
+        // Each pair shows up in both points' streams; only take it from the one with the lower index.
+        if let Some((j, dist)) = self.streams[i].find(|&(j, _)| j > i) {
+            self.queue.push(Reverse((dist, i, j)));
+        }
+    }
+}
+
+impl<'a, const N: usize, T: PrimInt> Iterator for ClosestPairs<'a, N, T> {
+    type Item = (usize, usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((dist, i, j)) = self.queue.pop()?;
+        self.advance(i);
+        Some((i, j, dist))
+    }
+}
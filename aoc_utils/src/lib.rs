@@ -1,7 +1,19 @@
 //! A collection of commonly used types, utility functions, and re-exports of frequently used third-party crates.
 
+pub mod bits;
 mod cli;
+pub mod cuboid;
+pub mod disjoint;
+pub mod geom;
+pub mod graph;
 pub mod grid;
+pub mod kdtree;
+pub mod math;
+pub mod memo;
+pub mod par;
+pub mod ranges;
+pub mod search;
+pub mod vec;
 
 pub use {arrayvec, regex, scoped_threadpool};
 
@@ -0,0 +1,286 @@
+//! Linear and integer linear programming.
+//!
+//! Some puzzles boil down to "what's the cheapest way to hit these exact targets?" — for example, the fewest button
+//! presses needed to bring a set of counters to some values, when each button bumps several counters at once. Those
+//! are integer linear programs:
+//!
+//! ```text
+//! minimize    c·x
+//! subject to  Ax = b
+//!             x ≥ 0
+//!             x integer
+//! ```
+//!
+//! [`minimize`] solves the _relaxation_ of such a problem (where `x` may be fractional) with the simplex method, and
+//! [`minimize_int`] uses it to solve the integer version with branch-and-bound. All arithmetic is done with exact
+//! [`Rational`] numbers, so there are no rounding issues; these solvers are meant for the small problems that puzzles
+//! tend to produce, not for anything large.
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::math::ilp::{Outcome, minimize_int};
+//!
+//! // Three buttons: one adds 1 to counters 0 and 1, one adds 1 to counter 0, and one adds 1 to counter 1. What's the
+//! // fewest presses needed to bring the counters to {3, 5}?
+//! let a = [vec![1, 1, 0], vec![1, 0, 1]];
+//! let outcome = minimize_int(&[1, 1, 1], &a, &[3, 5]);
+//! assert_eq!(outcome, Outcome::Optimal { value: 5, x: vec![3, 0, 2] });
+//! ```
+
+use super::{Integer, Rational};
+
+/// The result of a linear program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<V> {
+    /// An optimal solution was found, with the given objective value.
+    Optimal { value: V, x: Vec<V> },
+    /// No solution satisfies all of the constraints.
+    Infeasible,
+    /// The objective can be made arbitrarily small.
+    Unbounded,
+}
+
+impl<V> Outcome<V> {
+    /// Gets the optimal objective value, if there is one.
+    pub fn value(&self) -> Option<&V> {
+        match self {
+            Outcome::Optimal { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Gets the optimal solution, if there is one.
+    pub fn solution(&self) -> Option<&[V]> {
+        match self {
+            Outcome::Optimal { x, .. } => Some(x),
+            _ => None,
+        }
+    }
+}
+
+/// A simplex tableau. Each row holds its coefficients followed by its right-hand side; `obj` holds the reduced costs,
+/// followed by the negated objective value.
+struct Tableau<T: Integer> {
+    rows: Vec<Vec<Rational<T>>>,
+    obj: Vec<Rational<T>>,
+    basis: Vec<usize>,
+}
+
+impl<T: Integer> Tableau<T> {
+    fn rhs(&self, r: usize) -> Rational<T> {
+        *self.rows[r].last().unwrap()
+    }
+
+    fn pivot(&mut self, r: usize, c: usize) {
+        let scale = self.rows[r][c].recip().expect("pivot element should not be zero");
+        for v in &mut self.rows[r] {
+            *v *= scale;
+        }
+
+        let pivot_row = self.rows[r].clone();
+        let others = self.rows.iter_mut().enumerate().filter(|&(i, _)| i != r).map(|(_, row)| row);
+        for row in others.chain(std::iter::once(&mut self.obj)) {
+            let factor = row[c];
+            if !factor.is_zero() {
+                for (v, &p) in row.iter_mut().zip(&pivot_row) {
+                    *v -= factor * p;
+                }
+            }
+        }
+
+        self.basis[r] = c;
+    }
+
+    /// Pivots until the objective can't be improved, only allowing the first `cols` columns to enter the basis. Uses
+    /// Bland's rule to avoid cycling. Returns `false` if the objective is unbounded.
+    fn optimize(&mut self, cols: usize) -> bool {
+        loop {
+            let Some(c) = (0..cols).find(|&j| self.obj[j].is_negative()) else {
+                return true;
+            };
+
+            let mut leaving: Option<(usize, Rational<T>)> = None;
+            for r in 0..self.rows.len() {
+                let a = self.rows[r][c];
+                if a.is_positive() {
+                    let ratio = self.rhs(r) / a;
+                    let better = match leaving {
+                        None => true,
+                        Some((l, best)) => ratio < best || (ratio == best && self.basis[r] < self.basis[l]),
+                    };
+                    if better {
+                        leaving = Some((r, ratio));
+                    }
+                }
+            }
+
+            match leaving {
+                Some((r, _)) => self.pivot(r, c),
+                None => return false,
+            }
+        }
+    }
+}
+
+/// Solves the linear program `minimize c·x subject to Ax = b, x ≥ 0` (where `x` may be fractional) using the two-phase
+/// simplex method.
+///
+/// # Panics
+///
+/// This function panics if the dimensions of `c`, `a`, and `b` do not agree, or if intermediate values overflow.
+pub fn minimize<T: Integer>(c: &[Rational<T>], a: &[Vec<Rational<T>>], b: &[Rational<T>]) -> Outcome<Rational<T>> {
+    let n = c.len();
+    let m = a.len();
+    assert_eq!(m, b.len(), "problem must have one right-hand side value per constraint");
+
+    // Phase 1: add an artificial variable to each row, and minimize their sum to find a feasible starting point. Each
+    // row is flipped if needed so that its right-hand side is non-negative; then the artificials make a valid basis.
+    let mut rows = Vec::with_capacity(m);
+    for (i, (row, &rhs)) in a.iter().zip(b).enumerate() {
+        assert_eq!(row.len(), n, "all constraints must have one coefficient per variable");
+        let sign = if rhs.is_negative() { -Rational::ONE } else { Rational::ONE };
+        let mut t = row.iter().map(|&v| v * sign).collect::<Vec<_>>();
+        t.extend((0..m).map(|j| if i == j { Rational::ONE } else { Rational::ZERO }));
+        t.push(rhs * sign);
+        rows.push(t);
+    }
+
+    let mut obj = vec![Rational::ZERO; n + m + 1];
+    for row in &rows {
+        for j in (0..n).chain([n + m]) {
+            obj[j] -= row[j];
+        }
+    }
+
+    let mut tab = Tableau { rows, obj, basis: (n..n + m).collect() };
+    tab.optimize(n + m);
+    if !tab.obj[n + m].is_zero() {
+        return Outcome::Infeasible;
+    }
+
+    // Any artificial variables still in the basis are zero. Swap them out for a real variable if we can; if not, the
+    // row is a linear combination of the others and can be dropped.
+    let mut r = 0;
+    while r < tab.rows.len() {
+        if tab.basis[r] >= n {
+            if let Some(j) = (0..n).find(|&j| !tab.rows[r][j].is_zero()) {
+                tab.pivot(r, j);
+            } else {
+                tab.rows.remove(r);
+                tab.basis.remove(r);
+                continue;
+            }
+        }
+        r += 1;
+    }
+
+    // Phase 2: swap in the real objective, expressed in terms of the non-basic variables, and optimize that.
+    tab.obj = vec![Rational::ZERO; n + m + 1];
+    tab.obj[..n].copy_from_slice(c);
+    for (row, &bv) in tab.rows.iter().zip(&tab.basis) {
+        for (o, &v) in tab.obj.iter_mut().zip(row) {
+            *o -= c[bv] * v;
+        }
+    }
+
+    if !tab.optimize(n) {
+        return Outcome::Unbounded;
+    }
+
+    let mut x = vec![Rational::ZERO; n];
+    for (r, &bv) in tab.basis.iter().enumerate() {
+        x[bv] = tab.rhs(r);
+    }
+
+    Outcome::Optimal { value: -tab.obj[n + m], x }
+}
+
+/// A bound on a single variable added while branching.
+#[derive(Clone, Copy)]
+enum Bound<T> {
+    AtMost(usize, T),
+    AtLeast(usize, T),
+}
+
+/// Solves the integer linear program `minimize c·x subject to Ax = b, x ≥ 0, x integer` using branch-and-bound.
+///
+/// Each branch solves a [relaxation][minimize] of the problem. Whenever the relaxation's solution has a fractional
+/// variable `xⱼ = v`, the problem is split into two: one where `xⱼ ≤ ⌊v⌋`, and one where `xⱼ ≥ ⌈v⌉`. Branches whose
+/// relaxation can't beat the best integer solution found so far are skipped.
+///
+/// Branch-and-bound is only guaranteed to finish when the feasible region is bounded (as it is in the usual puzzle
+/// setup, where every variable appears in some constraint with only non-negative coefficients). On an unbounded region
+/// with no integer solutions, such as `2x - 2y = 1`, it will keep branching forever.
+///
+/// # Panics
+///
+/// This function panics if the dimensions of `c`, `a`, and `b` do not agree, or if intermediate values overflow.
+pub fn minimize_int<T: Integer>(c: &[T], a: &[Vec<T>], b: &[T]) -> Outcome<T> {
+    let n = c.len();
+    let to_rational = |row: &[T]| row.iter().map(|&v| Rational::int(v)).collect::<Vec<_>>();
+    let c = to_rational(c);
+    let a = a.iter().map(|row| to_rational(row)).collect::<Vec<_>>();
+    let b = to_rational(b);
+
+    let mut best: Option<(T, Vec<T>)> = None;
+    let mut stack = vec![Vec::<Bound<T>>::new()];
+    while let Some(bounds) = stack.pop() {
+        // Each bound becomes an extra constraint with its own slack variable: `xⱼ + s = k` or `xⱼ - s = k`.
+        let k = bounds.len();
+        let mut c2 = c.clone();
+        c2.extend(std::iter::repeat_n(Rational::ZERO, k));
+        let mut a2 = a.clone();
+        for row in &mut a2 {
+            row.extend(std::iter::repeat_n(Rational::ZERO, k));
+        }
+        let mut b2 = b.clone();
+        for (i, &bound) in bounds.iter().enumerate() {
+            let mut row = vec![Rational::ZERO; n + k];
+            let (j, v, slack) = match bound {
+                Bound::AtMost(j, v) => (j, v, Rational::ONE),
+                Bound::AtLeast(j, v) => (j, v, -Rational::ONE),
+            };
+            row[j] = Rational::ONE;
+            row[n + i] = slack;
+            a2.push(row);
+            b2.push(Rational::int(v));
+        }
+
+        let (value, x) = match minimize(&c2, &a2, &b2) {
+            Outcome::Optimal { value, x } => (value, x),
+            Outcome::Infeasible => continue,
+            Outcome::Unbounded => return Outcome::Unbounded,
+        };
+
+        // The objective of an integer solution is always an integer, so there's no point continuing unless the
+        // relaxation can get to at least one less than our current best.
+        if let Some((best_value, _)) = &best
+            && value.ceil() >= *best_value
+        {
+            continue;
+        }
+
+        match (0..n).find(|&j| !x[j].is_int()) {
+            Some(j) => {
+                let mut lo = bounds.clone();
+                let mut hi = bounds;
+                lo.push(Bound::AtMost(j, x[j].floor()));
+                hi.push(Bound::AtLeast(j, x[j].ceil()));
+                // Pushed last so it's explored first: rounding down tends to find a good solution sooner when
+                // minimizing.
+                stack.push(hi);
+                stack.push(lo);
+            },
+            None => {
+                let x = x[..n].iter().map(|v| v.to_int().unwrap()).collect();
+                best = Some((value.to_int().unwrap(), x));
+            },
+        }
+    }
+
+    match best {
+        Some((value, x)) => Outcome::Optimal { value, x },
+        None => Outcome::Infeasible,
+    }
+}
@@ -0,0 +1,268 @@
+//! Exact solutions to systems of linear equations.
+//!
+//! Systems are given as a coefficient matrix `A` (a list of rows) and a right-hand side `b`, and solved for `x` in
+//! `Ax = b` using Gauss-Jordan elimination over [`Rational`] numbers, so no precision is lost along the way.
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::math::Rational;
+//! use aoc_utils::math::linalg::{Solution, solve_int};
+//!
+//! // 94a + 22b = 8400
+//! // 34a + 67b = 5400
+//! let solution = solve_int(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+//! assert_eq!(solution, Solution::Unique(vec![Rational::int(80), Rational::int(40)]));
+//!
+//! // a + b = 2 has infinitely many solutions, but only three which use non-negative integers.
+//! let solution = solve_int(&[vec![1, 1]], &[2]);
+//! let ints = solution.nonnegative_integer_solutions(&[2, 2]).collect::<Vec<_>>();
+//! assert_eq!(ints, [vec![2, 0], vec![1, 1], vec![0, 2]]);
+//! ```
+
+use super::{Integer, Rational};
+
+/// The result of solving a system of linear equations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution<T: Integer = i64> {
+    /// The system is inconsistent: no values satisfy every equation.
+    None,
+    /// The system has exactly one solution.
+    Unique(Vec<Rational<T>>),
+    /// The system has infinitely many solutions.
+    Infinite(Parametric<T>),
+}
+
+/// The infinitely many solutions to an underdetermined system of linear equations.
+///
+/// Every solution can be written as a [particular solution][Parametric::particular] plus some combination of the
+/// [basis vectors][Parametric::basis], with one basis vector per **free variable**. Free variables can be given any
+/// value; each of the other variables is then determined by the values of the free ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parametric<T: Integer = i64> {
+    particular: Vec<Rational<T>>,
+    free: Vec<usize>,
+    basis: Vec<Vec<Rational<T>>>,
+}
+
+impl<T: Integer> Parametric<T> {
+    /// Gets the solution obtained by setting every free variable to zero.
+    pub fn particular(&self) -> &[Rational<T>] {
+        &self.particular
+    }
+
+    /// Gets the indices of the free variables.
+    pub fn free_vars(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// Gets the basis vectors of the solution space, one for each free variable (in the same order as
+    /// [`free_vars`][Self::free_vars]).
+    pub fn basis(&self) -> &[Vec<Rational<T>>] {
+        &self.basis
+    }
+
+    /// Gets the number of free variables.
+    pub fn dimension(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Computes the solution where each free variable takes the corresponding value in `params`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of parameters doesn't match the number of free variables.
+    pub fn evaluate(&self, params: &[Rational<T>]) -> Vec<Rational<T>> {
+        assert_eq!(params.len(), self.free.len(), "one parameter is required per free variable");
+        let mut x = self.particular.clone();
+        for (&t, v) in params.iter().zip(&self.basis) {
+            for (xi, &vi) in x.iter_mut().zip(v) {
+                *xi += t * vi;
+            }
+        }
+        x
+    }
+}
+
+impl<T: Integer> Solution<T> {
+    /// Returns `true` if the system has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        matches!(self, Solution::Unique(_))
+    }
+
+    /// Gets the unique solution, if there is one.
+    pub fn unique(&self) -> Option<&[Rational<T>]> {
+        match self {
+            Solution::Unique(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over all of the solutions made up only of non-negative integers.
+    ///
+    /// When there are infinitely many solutions, the free variables are searched exhaustively, so they each need an
+    /// inclusive upper bound. `bounds` holds an upper bound for every variable (only those for the free variables are
+    /// used). In button-pressing puzzles, for example, a button can't be pressed more times than the smallest target
+    /// it contributes to.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if there are infinitely many solutions and `bounds` is shorter than the number of
+    /// variables.
+    pub fn nonnegative_integer_solutions(&self, bounds: &[T]) -> impl Iterator<Item = Vec<T>> {
+        // Treat the unique case as a parametric solution with no free variables, so there's only one code path.
+        let (particular, free, basis) = match self {
+            Solution::None => (Vec::new(), Vec::new(), Vec::new()),
+            Solution::Unique(x) => (x.clone(), Vec::new(), Vec::new()),
+            Solution::Infinite(p) => (p.particular.clone(), p.free.clone(), p.basis.clone()),
+        };
+
+        let maxes = free.iter().map(|&f| bounds[f]).collect::<Vec<_>>();
+        let mut params = Some(vec![T::ZERO; free.len()]).filter(|_| !matches!(self, Solution::None));
+
+        std::iter::from_fn(move || {
+            while let Some(curr) = params.as_mut() {
+                // Compute the solution for the current parameters before advancing to the next ones.
+                let mut x = particular.clone();
+                for (&t, v) in curr.iter().zip(&basis) {
+                    for (xi, &vi) in x.iter_mut().zip(v) {
+                        *xi += vi * t;
+                    }
+                }
+
+                // Advance like an odometer; once every digit rolls over, we're done.
+                let mut i = 0;
+                loop {
+                    if i == curr.len() {
+                        params = None;
+                        break;
+                    } else if curr[i] < maxes[i] {
+                        curr[i] = curr[i] + T::ONE;
+                        break;
+                    } else {
+                        curr[i] = T::ZERO;
+                        i += 1;
+                    }
+                }
+
+                let ints = x.iter().map(|r| r.to_int().filter(|&n| n >= T::ZERO)).collect::<Option<Vec<_>>>();
+                if ints.is_some() {
+                    return ints;
+                }
+            }
+
+            None
+        })
+    }
+}
+
+/// Reduces an augmented matrix to reduced row echelon form in place using Gauss-Jordan elimination, returning the
+/// column index of each row's pivot.
+///
+/// Every row must be the same length. Only the first `cols` columns are used for pivots; any columns after those (such
+/// as the right-hand side of an augmented matrix) are just carried along. Rows left without a pivot end up at the
+/// bottom of the matrix.
+pub fn rref<T: Integer>(m: &mut [Vec<Rational<T>>], cols: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    let mut row = 0;
+    for col in 0..cols {
+        if row == m.len() {
+            break;
+        }
+
+        // Find a row with a non-zero entry in this column to use as a pivot.
+        let Some(p) = (row..m.len()).find(|&r| !m[r][col].is_zero()) else {
+            continue;
+        };
+        m.swap(row, p);
+
+        // Scale it so the pivot is 1...
+        let scale = m[row][col].recip().unwrap();
+        for v in m[row].iter_mut() {
+            *v *= scale;
+        }
+
+        // ...and eliminate this column from every other row.
+        for r in 0..m.len() {
+            if r != row && !m[r][col].is_zero() {
+                let factor = m[r][col];
+                for c in 0..m[r].len() {
+                    let sub = factor * m[row][c];
+                    m[r][c] -= sub;
+                }
+            }
+        }
+
+        pivots.push(col);
+        row += 1;
+    }
+
+    pivots
+}
+
+/// Solves the system of linear equations `Ax = b`.
+///
+/// `a` holds one row of coefficients for each equation; every row must have the same length (the number of
+/// variables).
+///
+/// # Panics
+///
+/// This function panics if `a` and `b` have different lengths, or if the rows of `a` are not all the same length. It
+/// may also panic if any intermediate values overflow.
+pub fn solve<T: Integer>(a: &[Vec<Rational<T>>], b: &[Rational<T>]) -> Solution<T> {
+    assert_eq!(a.len(), b.len(), "system must have one right-hand side value per equation");
+    let n = a.first().map_or(0, |row| row.len());
+
+    let mut m = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| {
+            assert_eq!(row.len(), n, "all equations must have the same number of coefficients");
+            let mut row = row.clone();
+            row.push(rhs);
+            row
+        })
+        .collect::<Vec<_>>();
+
+    let pivots = rref(&mut m, n);
+
+    // Any leftover rows are all zeroes on the left; if their right-hand side isn't zero too, we have `0 = c`.
+    if m[pivots.len()..].iter().any(|row| !row[n].is_zero()) {
+        return Solution::None;
+    }
+
+    let mut particular = vec![Rational::ZERO; n];
+    for (row, &col) in pivots.iter().enumerate() {
+        particular[col] = m[row][n];
+    }
+
+    if pivots.len() == n {
+        return Solution::Unique(particular);
+    }
+
+    // Each free variable contributes a basis vector: set it to 1, then each pivot variable must compensate for it.
+    let free = (0..n).filter(|c| !pivots.contains(c)).collect::<Vec<_>>();
+    let basis = free
+        .iter()
+        .map(|&f| {
+            let mut v = vec![Rational::ZERO; n];
+            v[f] = Rational::ONE;
+            for (row, &col) in pivots.iter().enumerate() {
+                v[col] = -m[row][f];
+            }
+            v
+        })
+        .collect();
+
+    Solution::Infinite(Parametric { particular, free, basis })
+}
+
+/// Solves the system of linear equations `Ax = b`, where all coefficients are integers. See [`solve`].
+pub fn solve_int<T: Integer>(a: &[Vec<T>], b: &[T]) -> Solution<T> {
+    let a = a
+        .iter()
+        .map(|row| row.iter().map(|&v| Rational::int(v)).collect())
+        .collect::<Vec<_>>();
+    let b = b.iter().map(|&v| Rational::int(v)).collect::<Vec<_>>();
+    solve(&a, &b)
+}
@@ -0,0 +1,152 @@
+//! Number types and numeric algorithms.
+
+pub mod ilp;
+pub mod linalg;
+pub mod num;
+pub mod rational;
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::num::ParseIntError;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::str::FromStr;
+
+pub use self::rational::Rational;
+
+/// A primitive integer type, signed or unsigned.
+///
+/// This trait exists so that the functions in this module can be generic over whichever integer type a puzzle happens
+/// to use. It is implemented for all of Rust's integer primitives.
+pub trait PrimInt:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + Debug
+    + Display
+    + FromStr<Err = ParseIntError>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// The number zero.
+    const ZERO: Self;
+
+    /// The number one.
+    const ONE: Self;
+
+    /// The number two.
+    const TWO: Self;
+
+    /// Checked integer addition. Returns `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer subtraction. Returns `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer multiplication. Returns `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer division. Returns `None` if `rhs` is zero or on overflow.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Checked integer remainder. Returns `None` if `rhs` is zero or on overflow.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+
+    /// Euclidean division: the quotient, rounded such that the remainder is never negative.
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// The (never negative) remainder of Euclidean division.
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// Converts this number to the nearest [`f64`].
+    fn to_f64(self) -> f64;
+
+    /// Converts this number to a [`usize`], if it fits.
+    fn to_usize(self) -> Option<usize>;
+
+    /// Converts a [`usize`] to this type, if it fits.
+    fn from_usize(n: usize) -> Option<Self>;
+}
+
+/// A primitive signed integer type.
+///
+/// This trait exists so that the types and functions in this module can be generic over the integer widths that puzzles
+/// tend to need (usually [`i64`], sometimes [`i128`] when things get big). It is implemented for all of Rust's signed
+/// integer primitives.
+pub trait Integer: PrimInt + Neg<Output = Self> {
+    /// Checked negation. Returns `None` on overflow.
+    fn checked_neg(self) -> Option<Self>;
+
+    /// The absolute value of this number.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_prim_int {
+    ($($t:ty),*) => {
+        $(impl PrimInt for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TWO: Self = 2;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_div(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_rem(self, rhs)
+            }
+
+            fn div_euclid(self, rhs: Self) -> Self {
+                <$t>::div_euclid(self, rhs)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn to_usize(self) -> Option<usize> {
+                usize::try_from(self).ok()
+            }
+
+            fn from_usize(n: usize) -> Option<Self> {
+                <$t>::try_from(n).ok()
+            }
+        })*
+    };
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        })*
+    };
+}
+
+impl_prim_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_integer!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,250 @@
+//! Number theory: divisibility, modular arithmetic, and friends.
+//!
+//! Everything here is generic over Rust's primitive integers. Functions which need negative numbers along the way (like
+//! [`egcd`]) require a signed [`Integer`]; the rest work with any [`PrimInt`].
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::math::num::{crt, lcm, mod_inv};
+//!
+//! // Two things which repeat every 101 and 103 steps will both be back where they started after 10,403 steps...
+//! assert_eq!(lcm(101, 103), 10_403);
+//!
+//! // ...so if the first is at step 12 of its cycle when the second is at step 75, this happens once in every 10,403:
+//! let (t, m) = crt([(12, 101), (75, 103)]).unwrap();
+//! assert_eq!((t, m), (2032, 10_403));
+//!
+//! assert_eq!(mod_inv(3, 7), Some(5)); // 3 × 5 = 15 ≡ 1 (mod 7)
+//! ```
+
+use super::{Integer, PrimInt};
+
+/// Returns the absolute value of a number, which may or may not be signed.
+fn abs<T: PrimInt>(n: T) -> T {
+    if n < T::ZERO { T::ZERO - n } else { n }
+}
+
+/// Returns the absolute value of a number, or `None` if it doesn't fit (i.e., for a signed type's `MIN`).
+fn checked_abs<T: PrimInt>(n: T) -> Option<T> {
+    if n < T::ZERO { T::ZERO.checked_sub(n) } else { Some(n) }
+}
+
+/// Computes the greatest common divisor of two numbers. The result is never negative.
+///
+/// `gcd(0, 0)` is zero.
+///
+/// # Panics
+///
+/// This function panics if the result overflows. See [`checked_gcd`].
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("attempt to compute gcd with overflow")
+}
+
+/// Computes the greatest common divisor of two numbers, returning `None` if the result overflows.
+///
+/// The only time that happens is when the result would be the absolute value of a signed type's `MIN`, which is too
+/// big to fit (e.g., `gcd(i64::MIN, 0)` or `gcd(i64::MIN, i64::MIN)`).
+///
+/// ```
+/// # use aoc_utils::math::num::checked_gcd;
+/// assert_eq!(checked_gcd(12, -18), Some(6));
+/// assert_eq!(checked_gcd(i64::MIN, -1), Some(1));
+/// assert_eq!(checked_gcd(i64::MIN, i64::MIN), None);
+/// ```
+pub fn checked_gcd<T: PrimInt>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // The only way for this to fail (since `b` isn't zero) is `MIN % -1`, which overflows despite the answer
+        // being zero.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    checked_abs(a)
+}
+
+/// Computes the least common multiple of two numbers. The result is never negative.
+///
+/// If either number is zero, the result is zero.
+///
+/// # Panics
+///
+/// This function panics if the result overflows.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    abs(a / gcd(a, b))
+        .checked_mul(abs(b))
+        .expect("attempt to compute lcm with overflow")
+}
+
+/// The extended Euclidean algorithm.
+///
+/// Returns `(g, x, y)` such that `g = gcd(a, b)` and `ax + by = g`. `g` is never negative.
+pub fn egcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::ONE, T::ZERO);
+    let (mut y0, mut y1) = (T::ZERO, T::ONE);
+    while r1 != T::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < T::ZERO { (-r0, -x0, -y0) } else { (r0, x0, y0) }
+}
+
+/// Computes the modular multiplicative inverse of `a` modulo `m`: the number `x` in `0..m` for which `ax ≡ 1 (mod m)`.
+///
+/// Returns `None` if no inverse exists (when `a` and `m` are not coprime).
+///
+/// # Panics
+///
+/// This function panics if `m` is not positive.
+pub fn mod_inv<T: Integer>(a: T, m: T) -> Option<T> {
+    assert!(m > T::ZERO, "modulus must be positive");
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// Computes `(a + b) mod m` without overflowing, for `a` and `b` already in `0..m`.
+fn add_mod<T: PrimInt>(a: T, b: T, m: T) -> T {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// Computes `(a × b) mod m` without overflowing, for `a` and `b` already in `0..m`.
+fn mul_mod<T: PrimInt>(mut a: T, mut b: T, m: T) -> T {
+    if let Some(ab) = a.checked_mul(b) {
+        return ab % m;
+    }
+
+    // Fall back to "Russian peasant" multiplication, which only ever needs to add numbers smaller than `m`.
+    let mut result = T::ZERO;
+    while b > T::ZERO {
+        if b % T::TWO == T::ONE {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b = b / T::TWO;
+    }
+    result
+}
+
+/// Computes `base` raised to the power of `exp`, modulo `m`, using exponentiation by squaring. The result is in `0..m`.
+///
+/// Intermediate values never overflow, even when `m` is close to the largest value of `T`.
+///
+/// # Panics
+///
+/// This function panics if `m` is not positive or if `exp` is negative.
+pub fn mod_pow<T: PrimInt>(base: T, mut exp: T, m: T) -> T {
+    assert!(m > T::ZERO, "modulus must be positive");
+    assert!(exp >= T::ZERO, "exponent must not be negative");
+
+    let mut base = base.rem_euclid(m);
+    let mut result = T::ONE % m;
+    while exp > T::ZERO {
+        if exp % T::TWO == T::ONE {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp = exp / T::TWO;
+    }
+    result
+}
+
+/// Solves a system of congruences using the Chinese remainder theorem.
+///
+/// Each `(r, m)` pair represents the congruence `x ≡ r (mod m)`. The moduli do not need to be coprime. If the system has
+/// a solution, it is returned as `(x, l)`, where `l` is the least common multiple of the moduli and `x` is the smallest
+/// non-negative solution; every solution is then `x + kl` for some integer `k`. Returns `None` if the congruences
+/// contradict each other (for example, `x ≡ 0 (mod 2)` and `x ≡ 1 (mod 4)`).
+///
+/// An empty system has the solution `(0, 1)`.
+///
+/// # Panics
+///
+/// This function panics if any modulus is not positive, or if the least common multiple of the moduli overflows.
+pub fn crt<T: Integer>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut l = T::ONE;
+    for (r, m) in congruences {
+        assert!(m > T::ZERO, "modulus must be positive");
+
+        // We want `x + l·k ≡ r (mod m)`, i.e. `l·k ≡ r - x (mod m)`. That only has a solution when `gcd(l, m)` divides
+        // `r - x`, in which case we can divide everything through by the gcd and then invert `l`.
+        let g = gcd(l, m);
+        let diff = (r.rem_euclid(m) - x.rem_euclid(m)).rem_euclid(m);
+        if diff % g != T::ZERO {
+            return None;
+        }
+
+        let m_g = m / g;
+        let inv = mod_inv((l / g).rem_euclid(m_g), m_g).expect("l/g and m/g should be coprime");
+        let k = mul_mod((diff / g).rem_euclid(m_g), inv, m_g);
+
+        let new_l = (l / g).checked_mul(m).expect("attempt to compute crt with overflow");
+        x = add_mod(x, mul_mod(l % new_l, k, new_l), new_l);
+        l = new_l;
+    }
+
+    Some((x, l))
+}
+
+/// Computes the integer square root of `n`: the largest number whose square is no greater than `n`.
+///
+/// # Panics
+///
+/// This function panics if `n` is negative.
+pub fn isqrt<T: PrimInt>(n: T) -> T {
+    assert!(n >= T::ZERO, "cannot take the square root of a negative number");
+
+    // Binary search for the answer. The square root of `n` is never more than `n / 2 + 1`, which keeps `hi` from
+    // overflowing when we add one to it.
+    let mut lo = T::ZERO;
+    let mut hi = n / T::TWO + T::ONE;
+    while lo < hi {
+        let mid = hi - (hi - lo) / T::TWO; // rounds up, so the loop always makes progress
+        match mid.checked_mul(mid) {
+            Some(sq) if sq <= n => lo = mid,
+            _ => hi = mid - T::ONE,
+        }
+    }
+    lo
+}
+
+/// Computes the prime factorization of `n`, as a list of `(prime, exponent)` pairs in increasing order of prime.
+///
+/// `1` has no prime factors, so its factorization is empty. This uses trial division, so it is only quick for numbers
+/// without large prime factors (and for anything up to around `10^12` or so).
+///
+/// # Panics
+///
+/// This function panics if `n` is not positive.
+pub fn prime_factors<T: PrimInt>(mut n: T) -> Vec<(T, u32)> {
+    assert!(n > T::ZERO, "can only factorize positive numbers");
+
+    let mut factors = Vec::new();
+    let mut p = T::TWO;
+    while p.checked_mul(p).is_some_and(|sq| sq <= n) {
+        if n % p == T::ZERO {
+            let mut exp = 0;
+            while n % p == T::ZERO {
+                n = n / p;
+                exp += 1;
+            }
+            factors.push((p, exp));
+        }
+
+        // After 2, only odd numbers can be prime.
+        p = if p == T::TWO { p + T::ONE } else { p + T::TWO };
+    }
+
+    // Whatever's left over has no factors up to its square root, so it must be prime itself.
+    if n > T::ONE {
+        factors.push((n, 1));
+    }
+
+    factors
+}
@@ -0,0 +1,413 @@
+//! Exact rational arithmetic.
+
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display};
+use std::iter::{Product, Sum};
+use std::num::ParseIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::Integer;
+use super::num::checked_gcd;
+
+/// A rational number (a fraction).
+///
+/// Rationals are always stored in lowest terms, with a positive denominator. Arithmetic operators panic if the result
+/// would overflow the underlying integer type (or when dividing by zero); each has a `checked_*` counterpart which
+/// returns `None` instead.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::math::Rational;
+/// let a = Rational::new(6, -4).unwrap();
+/// assert_eq!((a.n(), a.d()), (-3, 2));
+///
+/// let b = "1/3".parse::<Rational>().unwrap();
+/// assert_eq!(a + b, Rational::new(-7, 6).unwrap());
+/// assert_eq!(a * 2, Rational::from(-3));
+/// assert!(a < b);
+/// assert_eq!(Rational::<i64>::new(i64::MAX, 1).unwrap().checked_add(Rational::ONE), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i64> {
+    n: T,
+    d: T,
+}
+
+/// An error representing failure to parse a string into a [`Rational`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseRationalError {
+    /// The numerator or denominator was not a valid integer.
+    #[error("invalid integer in rational number: {0}")]
+    InvalidInt(#[from] ParseIntError),
+
+    /// The denominator was zero.
+    #[error("rational number cannot have a denominator of zero")]
+    ZeroDenominator,
+}
+
+impl<T: Integer> Rational<T> {
+    /// The number zero.
+    pub const ZERO: Self = Rational { n: T::ZERO, d: T::ONE };
+
+    /// The number one.
+    pub const ONE: Self = Rational { n: T::ONE, d: T::ONE };
+
+    /// Gets the numerator of this [Rational] number.
+    pub fn n(&self) -> T {
+        self.n
+    }
+
+    /// Gets the denominator of this [Rational] number. It is always positive.
+    pub fn d(&self) -> T {
+        self.d
+    }
+
+    /// Creates a new [Rational] number with a denominator of 1.
+    pub fn int(n: T) -> Self {
+        Rational { n, d: T::ONE }
+    }
+
+    /// Creates a new [Rational] number in lowest terms, as long as the denominator is not zero (and as long as
+    /// normalizing it doesn't overflow).
+    ///
+    /// ```
+    /// # use aoc_utils::math::Rational;
+    /// assert_eq!(Rational::new(3, 0), None);
+    /// assert_eq!(Rational::new(i64::MIN, -1), None); // -i64::MIN doesn't fit in an i64
+    /// assert_eq!(Rational::new(i64::MIN, i64::MIN), None); // Neither does their GCD
+    /// assert_eq!(Rational::new(i64::MIN, 2), Some(Rational::from(i64::MIN / 2)));
+    /// ```
+    pub fn new(n: T, d: T) -> Option<Self> {
+        if d == T::ZERO {
+            return None;
+        }
+
+        let g = checked_gcd(n, d)?;
+        let (mut n, mut d) = (n / g, d / g);
+
+        // If the denominator is negative, flip signs to keep it positive.
+        if d < T::ZERO {
+            n = n.checked_neg()?;
+            d = d.checked_neg()?;
+        }
+
+        Some(Rational { n, d })
+    }
+
+    /// Returns the reciprocal of this [Rational] number, unless it is zero.
+    pub fn recip(self) -> Option<Self> {
+        Rational::new(self.d, self.n)
+    }
+
+    /// Returns `true` if this number is a whole number.
+    pub fn is_int(&self) -> bool {
+        self.d == T::ONE
+    }
+
+    /// Returns the integer representation of this [Rational] number, if it is a whole number.
+    pub fn to_int(self) -> Option<T> {
+        self.is_int().then_some(self.n)
+    }
+
+    /// Returns the largest integer less than or equal to this number.
+    pub fn floor(self) -> T {
+        self.n.div_euclid(self.d)
+    }
+
+    /// Returns the smallest integer greater than or equal to this number.
+    pub fn ceil(self) -> T {
+        if self.is_int() { self.n } else { self.floor() + T::ONE }
+    }
+
+    /// Returns the absolute value of this number.
+    pub fn abs(self) -> Self {
+        Rational { n: self.n.abs(), d: self.d }
+    }
+
+    /// Returns `true` if this number is less than zero.
+    pub fn is_negative(&self) -> bool {
+        self.n < T::ZERO
+    }
+
+    /// Returns `true` if this number is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.n > T::ZERO
+    }
+
+    /// Returns `true` if this number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.n == T::ZERO
+    }
+
+    /// Converts this number to the nearest [`f64`].
+    pub fn to_f64(self) -> f64 {
+        self.n.to_f64() / self.d.to_f64()
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        // Put both over their lowest common denominator first, to keep intermediate values as small as possible.
+        let g = checked_gcd(self.d, rhs.d)?;
+        let l = self.n.checked_mul(rhs.d / g)?;
+        let r = rhs.n.checked_mul(self.d / g)?;
+        Rational::new(l.checked_add(r)?, (self.d / g).checked_mul(rhs.d)?)
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancel common factors across the two fractions before multiplying. Denominators are never zero, so neither
+        // are these.
+        let g1 = checked_gcd(self.n, rhs.d)?;
+        let g2 = checked_gcd(rhs.n, self.d)?;
+        let n = (self.n / g1).checked_mul(rhs.n / g2)?;
+        let d = (self.d / g2).checked_mul(rhs.d / g1)?;
+        Rational::new(n, d)
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or on overflow.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip()?)
+    }
+
+    /// Checked negation. Returns `None` on overflow.
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational { n: self.n.checked_neg()?, d: self.d })
+    }
+}
+
+impl<T: Integer> Default for Rational<T> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<T: Integer> Display for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&self.n, f)?;
+        if self.d != T::ONE {
+            f.write_str("/")?;
+            Display::fmt(&self.d, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Integer> Debug for Rational<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Debug::fmt(&self.n, f)?;
+        if self.d != T::ONE {
+            f.write_str("/")?;
+            Debug::fmt(&self.d, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Integer> FromStr for Rational<T> {
+    type Err = ParseRationalError;
+
+    /// Parses a rational number from either a plain integer (`"-5"`) or a fraction (`"10/4"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((n, d)) => {
+                let n = n.trim().parse()?;
+                let d = d.trim().parse()?;
+                Rational::new(n, d).ok_or(ParseRationalError::ZeroDenominator)
+            },
+            None => Ok(Rational::int(s.trim().parse()?)),
+        }
+    }
+}
+
+/// Compares `an/ad` and `bn/bd` (with positive denominators) without any risk of overflow, by comparing their
+/// continued fraction expansions.
+fn cmp_fractions<T: Integer>(an: T, ad: T, bn: T, bd: T) -> Ordering {
+    let (qa, ra) = (an.div_euclid(ad), an.rem_euclid(ad));
+    let (qb, rb) = (bn.div_euclid(bd), bn.rem_euclid(bd));
+    match qa.cmp(&qb) {
+        Ordering::Equal => match (ra == T::ZERO, rb == T::ZERO) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Both fractional parts are in (0, 1); comparing their reciprocals flips the order.
+            (false, false) => cmp_fractions(bd, rb, ad, ra),
+        },
+        ord => ord,
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_fractions(self.n, self.d, other.n, other.d)
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+/// Implements an arithmetic operator and its assigning counterpart, for `Rational op Rational` and `Rational op T`.
+macro_rules! impl_rational_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, $checked:ident, $msg:literal) => {
+        impl<T: Integer> $op<Rational<T>> for Rational<T> {
+            type Output = Rational<T>;
+
+            fn $fn(self, rhs: Rational<T>) -> Self::Output {
+                self.$checked(rhs).expect($msg)
+            }
+        }
+
+        impl<T: Integer> $op<T> for Rational<T> {
+            type Output = Rational<T>;
+
+            fn $fn(self, rhs: T) -> Self::Output {
+                self.$checked(Rational::int(rhs)).expect($msg)
+            }
+        }
+
+        impl<T: Integer> $assign_op<Rational<T>> for Rational<T> {
+            fn $assign_fn(&mut self, rhs: Rational<T>) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+
+        impl<T: Integer> $assign_op<T> for Rational<T> {
+            fn $assign_fn(&mut self, rhs: T) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_rational_op!(Add, add, AddAssign, add_assign, checked_add, "attempt to add with overflow");
+impl_rational_op!(Sub, sub, SubAssign, sub_assign, checked_sub, "attempt to subtract with overflow");
+impl_rational_op!(Mul, mul, MulAssign, mul_assign, checked_mul, "attempt to multiply with overflow");
+impl_rational_op!(Div, div, DivAssign, div_assign, checked_div, "attempt to divide by zero or with overflow");
+
+/// Implements `T op Rational<T>` and `From<T> for Rational<T>` for a concrete integer type (which can't be done
+/// generically because of the orphan rule).
+macro_rules! impl_int_ops {
+    ($($t:ty),*) => {
+        $(
+            impl Add<Rational<$t>> for $t {
+                type Output = Rational<$t>;
+
+                fn add(self, rhs: Rational<$t>) -> Self::Output {
+                    Rational::int(self) + rhs
+                }
+            }
+
+            impl Sub<Rational<$t>> for $t {
+                type Output = Rational<$t>;
+
+                fn sub(self, rhs: Rational<$t>) -> Self::Output {
+                    Rational::int(self) - rhs
+                }
+            }
+
+            impl Mul<Rational<$t>> for $t {
+                type Output = Rational<$t>;
+
+                fn mul(self, rhs: Rational<$t>) -> Self::Output {
+                    Rational::int(self) * rhs
+                }
+            }
+
+            impl Div<Rational<$t>> for $t {
+                type Output = Rational<$t>;
+
+                fn div(self, rhs: Rational<$t>) -> Self::Output {
+                    Rational::int(self) / rhs
+                }
+            }
+
+            impl From<$t> for Rational<$t> {
+                fn from(value: $t) -> Self {
+                    Rational::int(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_ops!(i8, i16, i32, i64, i128, isize);
+
+/// Implements lossless conversions into a wider [`Rational`].
+macro_rules! impl_widen {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<$from> for Rational<$to> {
+                fn from(value: $from) -> Self {
+                    Rational::int(value.into())
+                }
+            }
+
+            impl From<Rational<$from>> for Rational<$to> {
+                fn from(value: Rational<$from>) -> Self {
+                    Rational { n: value.n.into(), d: value.d.into() }
+                }
+            }
+
+            impl TryFrom<Rational<$to>> for Rational<$from> {
+                type Error = std::num::TryFromIntError;
+
+                fn try_from(value: Rational<$to>) -> Result<Self, Self::Error> {
+                    Ok(Rational { n: value.n.try_into()?, d: value.d.try_into()? })
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(i32 => i64, i32 => i128, i64 => i128);
+
+impl<T: Integer> From<Rational<T>> for f64 {
+    fn from(value: Rational<T>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<T: Integer> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, b| a + b)
+    }
+}
+
+impl<'a, T: Integer> Sum<&'a Rational<T>> for Rational<T> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |a, &b| a + b)
+    }
+}
+
+impl<T: Integer> Product for Rational<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, b| a * b)
+    }
+}
+
+impl<'a, T: Integer> Product<&'a Rational<T>> for Rational<T> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |a, &b| a * b)
+    }
+}
@@ -0,0 +1,142 @@
+//! Memoization for recursive functions.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::hash::Hash;
+
+/// A cache of previously computed results, for memoizing recursive functions.
+///
+/// The tricky part about memoizing a recursive function in Rust is that the function needs to be able to check and
+/// update the cache from inside of itself, which usually means threading a `&mut HashMap` through every call.
+/// [`get_or_compute`][Memo::get_or_compute] handles that by lending the memo back to the function doing the computing,
+/// so it can recurse through the same cache.
+///
+/// A memo also keeps track of how many times it has been hit or missed, which can be printed with
+/// [`print_stats`][Memo::print_stats] to see how well the memoization is working.
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::memo::Memo;
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fib(memo, n - 1) + fib(memo, n - 2),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fib(&mut memo, 90), 2_880_067_194_370_816_120);
+/// assert_eq!(memo.len(), 91);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+/// A summary of how often a [`Memo`] has been used. See [`Memo::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MemoStats {
+    /// The number of lookups that found an already computed value.
+    pub hits: usize,
+    /// The number of lookups that had to compute a new value.
+    pub misses: usize,
+    /// The number of values currently stored.
+    pub size: usize,
+}
+
+impl<K, V> Memo<K, V> {
+    /// Creates a new, empty memo.
+    pub fn new() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The number of values currently stored in this memo.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Checks if this memo has no values stored in it.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Removes all stored values from this memo and resets its stats.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Gets a summary of how often this memo has been used.
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.cache.len(),
+        }
+    }
+
+    /// Prints this memo's [stats][Memo::stats], with a label, if [verbosity][crate::verbosity] is at least `level`.
+    pub fn print_stats(&self, level: u8, label: &str) {
+        crate::vprintln!(level, "{label}: {}", self.stats());
+    }
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    /// Gets the stored value for `key` without computing it. This doesn't count as a hit or a miss.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Stores a value for `key` ahead of time, like a base case. Returns the previously stored value, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.cache.insert(key, value)
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    /// Gets the stored value for `key`, or computes and stores it if there isn't one yet.
+    ///
+    /// The function doing the computing is given this memo and the key. It may call `get_or_compute` on the memo again
+    /// to recurse.
+    pub fn get_or_compute<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.hits + self.misses;
+        let rate = if total > 0 { self.hits as f64 / total as f64 * 100.0 } else { 0.0 };
+        write!(
+            f,
+            "{} entries, {} hits, {} misses ({rate:.1}% hit rate)",
+            self.size, self.hits, self.misses
+        )
+    }
+}
@@ -0,0 +1,124 @@
+//! Simple data-parallel helpers built on top of [`threadpool`][crate::threadpool].
+//!
+//! Each of these functions takes anything that can be iterated over (a slice, a `Vec`, [`Grid::positions`], ...),
+//! splits the items into contiguous chunks, and runs a function over every item in parallel. Results always come back
+//! in the same order as the items went in, so switching a sequential `.iter().map(...)` over to [`par_map`] doesn't
+//! change the output.
+//!
+//! [`Grid::positions`]: crate::Grid::positions
+//!
+//! # Example
+//!
+//! ```
+//! # use aoc_utils::Grid;
+//! # use aoc_utils::par::{par_count, par_map, par_sum};
+//! let nums = (1..=100u64).collect::<Vec<_>>();
+//! assert_eq!(par_map(&nums, |&n| n * n)[..3], [1, 4, 9]);
+//! assert_eq!(par_sum(&nums, |&n| n), 5050);
+//!
+//! let grid = Grid::from_lines(["#..#", ".##."]).unwrap();
+//! assert_eq!(par_count(grid.positions(), |pos| grid[pos] == '#'), 4);
+//! ```
+
+use std::iter::Sum;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// How many chunks to split work into for each thread in the pool. Using more than one gives threads that finish early
+/// a chance to pick up some of the slack when some items take much longer than others.
+const CHUNKS_PER_THREAD: usize = 8;
+
+/// Splits `items` into chunks and runs `f` on each of them in parallel. Returns each chunk's result, in order.
+fn par_chunks<I, C, F>(items: I, f: F) -> Vec<C>
+where
+    I: IntoIterator,
+    I::Item: Send,
+    C: Send,
+    F: Fn(Vec<I::Item>) -> C + Sync,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    if items.is_empty() {
+        return Vec::new();
+    }
+
+    let mut pool = crate::threadpool();
+    let num_chunks = (pool.thread_count() as usize * CHUNKS_PER_THREAD).min(items.len());
+    let chunk_size = items.len().div_ceil(num_chunks);
+
+    let mut items = items.into_iter();
+    let chunks = std::iter::from_fn(|| {
+        let chunk = items.by_ref().take(chunk_size).collect::<Vec<_>>();
+        (!chunk.is_empty()).then_some(chunk)
+    });
+
+    let mut results = (0..num_chunks).map(|_| None).collect::<Vec<_>>();
+    pool.scoped(|scope| {
+        for (chunk, slot) in chunks.zip(&mut results) {
+            let f = &f;
+            scope.execute(move || *slot = Some(f(chunk)));
+        }
+    });
+
+    results.into_iter().flatten().collect()
+}
+
+/// Applies `f` to every item in parallel, returning the results in the same order as the items.
+pub fn par_map<I, R, F>(items: I, f: F) -> Vec<R>
+where
+    I: IntoIterator,
+    I::Item: Send,
+    R: Send,
+    F: Fn(I::Item) -> R + Sync,
+{
+    let chunks = par_chunks(items, |chunk| chunk.into_iter().map(&f).collect::<Vec<_>>());
+    chunks.into_iter().flatten().collect()
+}
+
+/// Applies `f` to every item in parallel and adds up the results.
+pub fn par_sum<I, S, F>(items: I, f: F) -> S
+where
+    I: IntoIterator,
+    I::Item: Send,
+    S: Sum + Send,
+    F: Fn(I::Item) -> S + Sync,
+{
+    par_chunks(items, |chunk| chunk.into_iter().map(&f).sum::<S>()).into_iter().sum()
+}
+
+/// Counts the number of items that `f` returns `true` for, checking them in parallel.
+pub fn par_count<I, F>(items: I, f: F) -> usize
+where
+    I: IntoIterator,
+    I::Item: Send,
+    F: Fn(I::Item) -> bool + Sync,
+{
+    par_sum(items, |item| usize::from(f(item)))
+}
+
+/// Searches for any item that `f` returns `Some` for, checking them in parallel.
+///
+/// As soon as one thread finds a result, the others stop searching. That means that if there are several items that
+/// `f` would return `Some` for, there's no telling which one will be returned (it won't necessarily be the first).
+pub fn par_find_any<I, R, F>(items: I, f: F) -> Option<R>
+where
+    I: IntoIterator,
+    I::Item: Send,
+    R: Send,
+    F: Fn(I::Item) -> Option<R> + Sync,
+{
+    let found = AtomicBool::new(false);
+    let results = par_chunks(items, |chunk| {
+        for item in chunk {
+            if found.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            if let Some(res) = f(item) {
+                found.store(true, Ordering::Relaxed);
+                return Some(res);
+            }
+        }
+        None
+    });
+
+    results.into_iter().flatten().next()
+}
@@ -0,0 +1,404 @@
+//! Sets of integers stored as sorted, non-overlapping ranges.
+//!
+//! Lots of puzzles deal in huge ranges of numbers (ID ranges, seed ranges, coordinates along a line) where storing each
+//! number individually is out of the question. An [`IntervalSet`] stores them as a list of [`Interval`]s instead,
+//! merging any that overlap or touch so that the list stays as short as possible.
+
+use std::fmt::{self, Debug, Display};
+use std::ops::RangeInclusive;
+
+use crate::math::PrimInt;
+
+/// An inclusive range of integers.
+///
+/// Rust's [`RangeInclusive`] struct is a little bit awkward to work with, on account of being generic over any type. It
+/// exposes `.start()` and `.end()` methods, but has no way to mutate those values. It's meant mostly for looping
+/// through. An `Interval` can be converted to and from one whenever iteration is needed.
+///
+/// An interval is never empty: `start` is always less than or equal to `end`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    /// Creates a new interval from `start` to `end`, inclusive.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `start` is greater than `end`.
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "interval start must not be greater than its end");
+        Interval { start, end }
+    }
+
+    /// Creates a new interval containing a single number.
+    pub fn single(x: T) -> Self {
+        Interval { start: x, end: x }
+    }
+
+    /// The first number in this interval.
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The last number in this interval.
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    /// The number of numbers in this interval.
+    ///
+    /// # Panics
+    ///
+    /// This function panics in debug mode if the length does not fit in `T` (e.g., `0..=u64::MAX`).
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    /// Checks whether this interval contains the given number.
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// Checks whether this interval shares any numbers with another.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Gets the numbers that this interval has in common with another, if there are any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        self.overlaps(other).then(|| Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// Checks whether this interval overlaps or is directly adjacent to another, such that the two could be merged
+    /// into a single interval.
+    fn touches(&self, other: &Self) -> bool {
+        let (a, b) = if self.start <= other.start { (self, other) } else { (other, self) };
+        b.start <= a.end || a.end.checked_add(T::ONE) == Some(b.start)
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for Interval<T> {
+    /// Converts a [`RangeInclusive`] into an interval.
+    ///
+    /// # Panics
+    ///
+    /// This conversion panics if the range is empty.
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::new(*range.start(), *range.end())
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for RangeInclusive<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(w) = f.width() {
+            write!(f, "{:>w$}-{:<w$}", self.start, self.end)
+        } else {
+            write!(f, "{}-{}", self.start, self.end)
+        }
+    }
+}
+
+impl<T: Display> Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(w) = f.width() {
+            write!(f, "{:w$}..={:w$}", self.start, self.end)
+        } else {
+            write!(f, "{}..={}", self.start, self.end)
+        }
+    }
+}
+
+/// A set of integers, stored as a sorted list of non-overlapping [`Interval`]s.
+///
+/// Intervals which overlap or are directly adjacent are always merged together, so any given set of numbers has only one
+/// representation (i.e., two sets are equal exactly when they contain the same numbers).
+///
+/// # Example
+///
+/// ```
+/// # use aoc_utils::ranges::{Interval, IntervalSet};
+/// let mut set = IntervalSet::new();
+/// set.insert(3..=5);
+/// set.insert(10..=14);
+/// set.insert(12..=18);
+/// set.insert(6..=6);
+/// assert_eq!(set.intervals(), [Interval::new(3, 6), Interval::new(10, 18)]);
+///
+/// set.remove(15..=16);
+/// assert_eq!(set.intervals(), [Interval::new(3, 6), Interval::new(10, 14), Interval::new(17, 18)]);
+/// assert_eq!(set.len(), 11);
+///
+/// assert!(set.contains(13));
+/// assert!(!set.contains(15));
+/// assert_eq!(set.index_of(17), Some(2));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    /// Creates a new, empty set.
+    pub fn new() -> Self {
+        IntervalSet { intervals: Vec::new() }
+    }
+
+    /// Gets the sorted list of non-overlapping intervals that make up this set.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Returns an iterator over the intervals in this set, in ascending order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Returns `true` if this set does not contain any numbers.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Gets the number of (merged) intervals that make up this set.
+    pub fn num_intervals(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Gets the total number of numbers in this set.
+    ///
+    /// # Panics
+    ///
+    /// This function panics in debug mode if the total does not fit in `T`.
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::ZERO, |acc, i| acc + i.len())
+    }
+
+    /// Gets the smallest number in this set.
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Gets the largest number in this set.
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|i| i.end)
+    }
+
+    /// Finds the index (in [`intervals`][Self::intervals]) of the interval which contains the given number, if any.
+    pub fn index_of(&self, x: T) -> Option<usize> {
+        // Find the first interval that doesn't end before `x`; `x` is in the set if and only if it's in that one.
+        let i = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(i).filter(|i| i.contains(x)).map(|_| i)
+    }
+
+    /// Finds the interval which contains the given number, if any.
+    pub fn get(&self, x: T) -> Option<&Interval<T>> {
+        self.index_of(x).map(|i| &self.intervals[i])
+    }
+
+    /// Checks whether this set contains the given number.
+    pub fn contains(&self, x: T) -> bool {
+        self.index_of(x).is_some()
+    }
+
+    /// Checks whether this set contains every number in the given interval.
+    pub fn contains_all(&self, interval: impl Into<Interval<T>>) -> bool {
+        let interval = interval.into();
+        self.get(interval.start).is_some_and(|i| interval.end <= i.end)
+    }
+
+    /// Adds every number in the given interval to this set, merging it with any intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: impl Into<Interval<T>>) {
+        let new = interval.into();
+
+        // All the intervals in `lo..hi` overlap or touch the new one, and get replaced by a single merged interval.
+        let lo = self.intervals.partition_point(|i| i.end < new.start && !i.touches(&new));
+        let hi = self.intervals.partition_point(|i| i.start <= new.end || i.touches(&new));
+
+        let merged = if lo < hi {
+            Interval {
+                start: self.intervals[lo].start.min(new.start),
+                end: self.intervals[hi - 1].end.max(new.end),
+            }
+        } else {
+            new
+        };
+
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Removes every number in the given interval from this set.
+    pub fn remove(&mut self, interval: impl Into<Interval<T>>) {
+        let gone = interval.into();
+
+        // All the intervals in `lo..hi` overlap the removed one. At most two pieces of them can survive: the part of
+        // the first one before `gone` starts, and the part of the last one after `gone` ends.
+        let lo = self.intervals.partition_point(|i| i.end < gone.start);
+        let hi = self.intervals.partition_point(|i| i.start <= gone.end);
+        if lo == hi {
+            return;
+        }
+
+        let first = self.intervals[lo];
+        let last = self.intervals[hi - 1];
+        let before = (first.start < gone.start).then(|| Interval::new(first.start, gone.start - T::ONE));
+        let after = (last.end > gone.end).then(|| Interval::new(gone.end + T::ONE, last.end));
+
+        self.intervals.splice(lo..hi, before.into_iter().chain(after));
+    }
+
+    /// Creates a new set containing all of the numbers that are in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let (mut big, small) = if self.intervals.len() >= other.intervals.len() {
+            (self.clone(), other)
+        } else {
+            (other.clone(), self)
+        };
+
+        for &interval in &small.intervals {
+            big.insert(interval);
+        }
+
+        big
+    }
+
+    /// Creates a new set containing only the numbers that are in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        // Walk through both lists at once, always stepping past whichever interval ends first (since it can't overlap
+        // anything further along in the other list).
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+            if let Some(overlap) = x.intersection(&y) {
+                intervals.push(overlap);
+            }
+
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// Creates a new set containing the numbers that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &interval in &other.intervals {
+            result.remove(interval);
+        }
+        result
+    }
+
+    /// Splits an interval into pieces based on which parts of it are in this set.
+    ///
+    /// Returns a list of non-overlapping intervals which cover all of `interval`, in ascending order, each paired with
+    /// a boolean indicating whether or not that piece is in this set. Consecutive pieces alternate between inside and
+    /// outside.
+    ///
+    /// This is useful for puzzles which map ranges of numbers through a series of transformations: each piece of the
+    /// input range that falls within a known region gets mapped, and the rest are passed through or handled separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use aoc_utils::ranges::{Interval, IntervalSet};
+    /// let set = IntervalSet::from_iter([5..=9, 15..=19]);
+    /// let pieces = set.split(0..=16);
+    /// assert_eq!(pieces, [
+    ///     (Interval::new(0, 4), false),
+    ///     (Interval::new(5, 9), true),
+    ///     (Interval::new(10, 14), false),
+    ///     (Interval::new(15, 16), true),
+    /// ]);
+    /// ```
+    pub fn split(&self, interval: impl Into<Interval<T>>) -> Vec<(Interval<T>, bool)> {
+        let interval = interval.into();
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+
+        let mut pieces = Vec::new();
+        let mut next = Some(interval.start); // The start of the next piece; `None` once we've passed the end of `T`.
+        for overlap in self.intervals[lo..hi].iter().filter_map(|i| i.intersection(&interval)) {
+            if let Some(start) = next
+                && start < overlap.start
+            {
+                pieces.push((Interval::new(start, overlap.start - T::ONE), false));
+            }
+
+            pieces.push((overlap, true));
+            next = overlap.end.checked_add(T::ONE);
+        }
+
+        if let Some(start) = next
+            && start <= interval.end
+        {
+            pieces.push((Interval::new(start, interval.end), false));
+        }
+
+        pieces
+    }
+}
+
+impl<T: Display> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+impl<T: PrimInt, I: Into<Interval<T>>> Extend<I> for IntervalSet<T> {
+    fn extend<It: IntoIterator<Item = I>>(&mut self, iter: It) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: PrimInt, I: Into<Interval<T>>> FromIterator<I> for IntervalSet<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        // Sorting everything up front and merging in one pass is quicker than inserting one at a time, since each
+        // insertion would need to shift everything after it over.
+        let mut all = iter.into_iter().map(Into::into).collect::<Vec<Interval<T>>>();
+        all.sort_unstable_by_key(|i| i.start);
+
+        let mut intervals = Vec::<Interval<T>>::with_capacity(all.len());
+        for interval in all {
+            match intervals.last_mut() {
+                Some(current) if current.touches(&interval) => current.end = current.end.max(interval.end),
+                Some(_) | None => intervals.push(interval),
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = std::vec::IntoIter<Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
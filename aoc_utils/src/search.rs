@@ -0,0 +1,402 @@
+//! Generic search algorithms over arbitrary state spaces.
+//!
+//! Where [`graph::paths`][crate::graph::paths] searches over graphs whose nodes are all known up front, this module is
+//! for puzzles where the "graph" is implicit: each state knows how to produce the states that follow from it, and the
+//! search discovers them as it goes. Implement [`SearchProblem`] for a type describing the puzzle, then hand it to
+//! whichever algorithm suits:
+//!
+//! - [`dfs`] and [`bfs`] find any path or the path with the fewest steps, respectively;
+//! - [`dfs_all`] finds every path to a goal;
+//! - [`dijkstra`] and [`astar`] find the cheapest path, the latter using the problem's [heuristic][SearchProblem::heuristic];
+//! - [`ida_star`] finds the cheapest path using much less memory than A\*, at the cost of revisiting states;
+//! - [`beam_search`] only keeps the most promising few states at each step, for state spaces that are far too large to
+//!   search exhaustively (its answer isn't guaranteed to be the best one).
+//!
+//! # Example
+//!
+//! ```
+//! use aoc_utils::search::{SearchProblem, bfs, dijkstra};
+//!
+//! /// Reach a target number starting from 1, by either adding one or doubling.
+//! struct Reach(u32);
+//!
+//! impl SearchProblem for Reach {
+//!     type State = u32;
+//!     type Cost = u32;
+//!
+//!     fn successors(&self, &n: &u32) -> impl IntoIterator<Item = u32> {
+//!         [n + 1, n * 2]
+//!     }
+//!
+//!     fn is_goal(&self, &n: &u32) -> bool {
+//!         n == self.0
+//!     }
+//!
+//!     fn prune(&self, &n: &u32) -> bool {
+//!         n > self.0 // We can never come back down
+//!     }
+//! }
+//!
+//! let fewest = bfs(&Reach(10), 1).unwrap();
+//! assert_eq!(fewest.path, [1, 2, 4, 5, 10]);
+//! assert_eq!(fewest.cost, 4);
+//! # assert_eq!(dijkstra(&Reach(10), 1).unwrap().cost, 4);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::paths::Cost;
+
+/// A problem that can be solved by searching through a space of states.
+///
+/// Only [`successors`][Self::successors] and [`is_goal`][Self::is_goal] are required. By default, every step costs
+/// [one][Cost::ONE], the heuristic is zero (which makes [`astar`] behave exactly like [`dijkstra`]), and nothing is
+/// pruned.
+pub trait SearchProblem {
+    /// The type of each state in the search.
+    type State: Clone + Eq + Hash;
+
+    /// The type of the cost of moving from one state to another.
+    type Cost: Cost;
+
+    /// Returns all the states which can be reached in one step from the given state.
+    fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = Self::State>;
+
+    /// Checks if the given state is a goal state, ending the search.
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// The cost of moving from `from` to `to`, where `to` is one of `from`'s [successors][Self::successors].
+    fn cost(&self, from: &Self::State, to: &Self::State) -> Self::Cost {
+        let _ = (from, to);
+        Self::Cost::ONE
+    }
+
+    /// An estimate of the cost left to reach a goal from the given state.
+    ///
+    /// For [`astar`] and [`ida_star`] to find the cheapest path, this must never _overestimate_ the remaining cost.
+    fn heuristic(&self, state: &Self::State) -> Self::Cost {
+        let _ = state;
+        Self::Cost::ZERO
+    }
+
+    /// Checks if the given state should be thrown away without being looked at (e.g., because it can't possibly lead to
+    /// a goal). Pruned states are never checked against [`is_goal`][Self::is_goal] or expanded.
+    fn prune(&self, state: &Self::State) -> bool {
+        let _ = state;
+        false
+    }
+}
+
+/// A path to a goal found by a search.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Solution<S, C> {
+    /// Every state along the path, from the start state to the goal state (inclusive).
+    pub path: Vec<S>,
+    /// The total cost of the path.
+    pub cost: C,
+}
+
+impl<S, C> Solution<S, C> {
+    /// The goal state that this path ends at.
+    pub fn goal(&self) -> &S {
+        self.path.last().expect("a solution should have at least one state")
+    }
+}
+
+/// Every state discovered during a search, with links back to the states they were discovered from.
+struct Tree<S, C> {
+    nodes: Vec<(S, Option<usize>, C)>,
+}
+
+impl<S: Clone, C: Copy> Tree<S, C> {
+    fn new() -> Self {
+        Tree { nodes: Vec::new() }
+    }
+
+    fn push(&mut self, state: S, parent: Option<usize>, cost: C) -> usize {
+        self.nodes.push((state, parent, cost));
+        self.nodes.len() - 1
+    }
+
+    fn state(&self, i: usize) -> &S {
+        &self.nodes[i].0
+    }
+
+    fn cost(&self, i: usize) -> C {
+        self.nodes[i].2
+    }
+
+    fn solution(&self, mut i: usize) -> Solution<S, C> {
+        let cost = self.cost(i);
+        let mut path = vec![self.nodes[i].0.clone()];
+        while let Some(parent) = self.nodes[i].1 {
+            path.push(self.nodes[parent].0.clone());
+            i = parent;
+        }
+
+        path.reverse();
+        Solution { path, cost }
+    }
+}
+
+/// Finds a path from `start` to a goal using a depth-first search.
+///
+/// Successors are explored in the order they're returned in, and no state is visited more than once. The path that's
+/// found is whichever one the search happens to reach first, not necessarily the shortest or cheapest one.
+pub fn dfs<P: SearchProblem>(problem: &P, start: P::State) -> Option<Solution<P::State, P::Cost>> {
+    if problem.prune(&start) {
+        return None;
+    }
+
+    let mut tree = Tree::new();
+    let mut visited = HashSet::new();
+    let mut stack = vec![tree.push(start, None, P::Cost::ZERO)];
+
+    while let Some(i) = stack.pop() {
+        let state = tree.state(i).clone();
+        if !visited.insert(state.clone()) {
+            continue;
+        } else if problem.is_goal(&state) {
+            return Some(tree.solution(i));
+        }
+
+        let next = problem
+            .successors(&state)
+            .into_iter()
+            .filter(|s| !problem.prune(s) && !visited.contains(s))
+            .collect::<Vec<_>>();
+
+        // Push them backwards so that the first successor gets popped first.
+        for s in next.into_iter().rev() {
+            let cost = tree.cost(i) + problem.cost(&state, &s);
+            stack.push(tree.push(s, Some(i), cost));
+        }
+    }
+
+    None
+}
+
+/// Finds every path from `start` to a goal using a depth-first search.
+///
+/// Unlike the other searches, states may be visited any number of times, as long as they don't appear twice in the
+/// same path (so cycles are skipped). The search doesn't continue past goal states. Beware that the number of paths
+/// can grow very quickly; this is best suited to tree-shaped problems or ones with heavy [pruning][SearchProblem::prune].
+pub fn dfs_all<P: SearchProblem>(problem: &P, start: P::State) -> Vec<Solution<P::State, P::Cost>> {
+    fn recurse<P: SearchProblem>(
+        problem: &P,
+        path: &mut Vec<P::State>,
+        cost: P::Cost,
+        found: &mut Vec<Solution<P::State, P::Cost>>,
+    ) {
+        let state = path.last().unwrap().clone();
+        if problem.is_goal(&state) {
+            found.push(Solution { path: path.clone(), cost });
+            return;
+        }
+
+        for s in problem.successors(&state) {
+            if problem.prune(&s) || path.contains(&s) {
+                continue;
+            }
+
+            let next_cost = cost + problem.cost(&state, &s);
+            path.push(s);
+            recurse(problem, path, next_cost, found);
+            path.pop();
+        }
+    }
+
+    let mut found = Vec::new();
+    if !problem.prune(&start) {
+        recurse(problem, &mut vec![start], P::Cost::ZERO, &mut found);
+    }
+    found
+}
+
+/// Finds the path from `start` to a goal with the fewest steps, using a breadth-first search.
+///
+/// Step costs aren't taken into account when choosing a path, but the returned solution's cost is still the total cost
+/// of its steps.
+pub fn bfs<P: SearchProblem>(problem: &P, start: P::State) -> Option<Solution<P::State, P::Cost>> {
+    if problem.prune(&start) {
+        return None;
+    }
+
+    let mut tree = Tree::new();
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([tree.push(start, None, P::Cost::ZERO)]);
+
+    while let Some(i) = queue.pop_front() {
+        let state = tree.state(i).clone();
+        if problem.is_goal(&state) {
+            return Some(tree.solution(i));
+        }
+
+        for s in problem.successors(&state) {
+            if !problem.prune(&s) && visited.insert(s.clone()) {
+                let cost = tree.cost(i) + problem.cost(&state, &s);
+                queue.push_back(tree.push(s, Some(i), cost));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from `start` to a goal using Dijkstra's algorithm.
+pub fn dijkstra<P: SearchProblem>(problem: &P, start: P::State) -> Option<Solution<P::State, P::Cost>> {
+    best_first(problem, start, |_| P::Cost::ZERO)
+}
+
+/// Finds the cheapest path from `start` to a goal using the A\* algorithm, guided by the problem's
+/// [heuristic][SearchProblem::heuristic].
+pub fn astar<P: SearchProblem>(problem: &P, start: P::State) -> Option<Solution<P::State, P::Cost>> {
+    best_first(problem, start, |s| problem.heuristic(s))
+}
+
+/// Runs a best-first search, always expanding whichever state has the lowest cost so far plus `heuristic`.
+fn best_first<P: SearchProblem>(
+    problem: &P,
+    start: P::State,
+    heuristic: impl Fn(&P::State) -> P::Cost,
+) -> Option<Solution<P::State, P::Cost>> {
+    if problem.prune(&start) {
+        return None;
+    }
+
+    let mut tree = Tree::new();
+    let mut best = HashMap::from([(start.clone(), P::Cost::ZERO)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), tree.push(start, None, P::Cost::ZERO)))]);
+
+    while let Some(Reverse((_, i))) = queue.pop() {
+        let state = tree.state(i).clone();
+        let cost = tree.cost(i);
+        if best.get(&state).is_some_and(|&b| cost > b) {
+            continue; // We've already found a cheaper way to get here.
+        } else if problem.is_goal(&state) {
+            return Some(tree.solution(i));
+        }
+
+        for s in problem.successors(&state) {
+            let next_cost = cost + problem.cost(&state, &s);
+            if problem.prune(&s) || best.get(&s).is_some_and(|&b| b <= next_cost) {
+                continue;
+            }
+
+            best.insert(s.clone(), next_cost);
+            let priority = next_cost + heuristic(&s);
+            queue.push(Reverse((priority, tree.push(s, Some(i), next_cost))));
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from `start` to a goal using iterative deepening A\* (IDA\*).
+///
+/// This runs a series of depth-first searches, each one cut off once the cost so far plus the
+/// [heuristic][SearchProblem::heuristic] exceeds some bound; the bound is raised a bit each time until a goal is found.
+/// It only needs to remember the current path, so it uses very little memory compared to [`astar`], but it may visit
+/// the same states many times over.
+///
+/// If no goal is reachable and the state space is infinite, this function never returns.
+pub fn ida_star<P: SearchProblem>(problem: &P, start: P::State) -> Option<Solution<P::State, P::Cost>> {
+    /// Searches below the last state in `path`. Returns the cost of the goal if one was found within `bound` (leaving
+    /// its path in `path`), or otherwise the smallest cost that went past the bound (if any did).
+    fn search<P: SearchProblem>(
+        problem: &P,
+        path: &mut Vec<P::State>,
+        cost: P::Cost,
+        bound: P::Cost,
+    ) -> Result<P::Cost, Option<P::Cost>> {
+        let state = path.last().unwrap().clone();
+        let estimate = cost + problem.heuristic(&state);
+        if estimate > bound {
+            return Err(Some(estimate));
+        } else if problem.is_goal(&state) {
+            return Ok(cost);
+        }
+
+        let mut next_bound = None::<P::Cost>;
+        for s in problem.successors(&state) {
+            if problem.prune(&s) || path.contains(&s) {
+                continue;
+            }
+
+            let next_cost = cost + problem.cost(&state, &s);
+            path.push(s);
+            match search(problem, path, next_cost, bound) {
+                Ok(goal_cost) => return Ok(goal_cost),
+                Err(Some(over)) => next_bound = Some(next_bound.map_or(over, |b| b.min(over))),
+                Err(None) => {},
+            }
+            path.pop();
+        }
+
+        Err(next_bound)
+    }
+
+    if problem.prune(&start) {
+        return None;
+    }
+
+    let mut bound = problem.heuristic(&start);
+    let mut path = vec![start];
+    loop {
+        match search(problem, &mut path, P::Cost::ZERO, bound) {
+            Ok(cost) => return Some(Solution { path, cost }),
+            Err(Some(next_bound)) => bound = next_bound,
+            Err(None) => return None,
+        }
+    }
+}
+
+/// Searches for a path from `start` to a goal using a beam search, which only keeps the `width` most promising states
+/// (by cost so far plus [heuristic][SearchProblem::heuristic]) at each step.
+///
+/// This is a last resort for state spaces that are far too large to search properly. It's fast and uses a fixed amount
+/// of memory, but it can miss the cheapest path (or miss the goal altogether) if a good path passes through a state
+/// that didn't look promising at the time. Among the goals in the first step that reaches any, the cheapest one is
+/// returned.
+pub fn beam_search<P: SearchProblem>(
+    problem: &P,
+    start: P::State,
+    width: usize,
+) -> Option<Solution<P::State, P::Cost>> {
+    if problem.prune(&start) || width == 0 {
+        return None;
+    }
+
+    let mut tree = Tree::new();
+    let mut visited = HashSet::from([start.clone()]);
+    let mut layer = vec![tree.push(start, None, P::Cost::ZERO)];
+
+    while !layer.is_empty() {
+        let goal = layer
+            .iter()
+            .filter(|&&i| problem.is_goal(tree.state(i)))
+            .min_by_key(|&&i| tree.cost(i));
+        if let Some(&i) = goal {
+            return Some(tree.solution(i));
+        }
+
+        let mut next_layer = Vec::new();
+        for &i in &layer {
+            let state = tree.state(i).clone();
+            for s in problem.successors(&state) {
+                if !problem.prune(&s) && visited.insert(s.clone()) {
+                    let cost = tree.cost(i) + problem.cost(&state, &s);
+                    next_layer.push(tree.push(s, Some(i), cost));
+                }
+            }
+        }
+
+        next_layer.sort_by_cached_key(|&i| tree.cost(i) + problem.heuristic(tree.state(i)));
+        next_layer.truncate(width);
+        layer = next_layer;
+    }
+
+    None
+}
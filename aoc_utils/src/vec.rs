@@ -0,0 +1,341 @@
+//! Small 2D and 3D vectors of integers.
+//!
+//! These are for puzzles that do actual arithmetic on positions and velocities, where a plain [`GridIndex`] tuple isn't
+//! enough (e.g., because things can move in negative directions or wrap around). All of the usual operators are
+//! implemented between vectors of the same type (`+`, `-`, and their assigning forms) and between vectors and scalars
+//! (`*`, `/`, `%`, and their assigning forms; `scalar * vec` works too). Signed vectors can also be negated.
+//!
+//! # Example
+//!
+//! ```
+//! # use aoc_utils::vec::Vec2;
+//! let mut pos = Vec2::new(2, 4);
+//! let vel = "v=2,-3".parse::<Vec2<i32>>().unwrap();
+//!
+//! pos += vel * 5;
+//! assert_eq!(pos, Vec2::new(12, -11));
+//! assert_eq!(pos.rem_euclid(Vec2::new(11, 7)), Vec2::new(1, 3));
+//! assert_eq!(pos.manhattan(Vec2::ZERO), 23);
+//! ```
+
+use std::fmt::{self, Display};
+use std::iter::Sum;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use auto_ops::{impl_op_ex, impl_op_ex_commutative};
+use thiserror::Error;
+
+use crate::geom::Point;
+use crate::grid::{GridIndex, Pos};
+use crate::math::{Integer, PrimInt};
+
+/// A two-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec2<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A three-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vec3<T = i64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// An error that can occur when parsing a [`Vec2`] or [`Vec3`] from a string.
+#[derive(Debug, Error)]
+pub enum ParseVecError {
+    #[error("vector should contain exactly {0} comma-separated components")]
+    WrongCount(usize),
+    #[error("vector contains an invalid component: {0}")]
+    InvalidInt(#[from] ParseIntError),
+}
+
+/// The absolute difference between two numbers, without going below zero for unsigned types.
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// Parses `N` comma-separated integers, optionally prefixed by a label and an equals sign (like `p=1,2`).
+fn parse_components<T: PrimInt, const N: usize>(s: &str) -> Result<[T; N], ParseVecError> {
+    let s = s.split_once('=').map_or(s, |(_, rest)| rest);
+    let mut bits = s.split(',');
+    let mut out = [T::ZERO; N];
+    for c in &mut out {
+        *c = bits.next().ok_or(ParseVecError::WrongCount(N))?.trim().parse()?;
+    }
+
+    match bits.next() {
+        None => Ok(out),
+        Some(_) => Err(ParseVecError::WrongCount(N)),
+    }
+}
+
+impl<T: PrimInt> Vec2<T> {
+    /// The zero vector.
+    pub const ZERO: Self = Vec2 { x: T::ZERO, y: T::ZERO };
+
+    /// Creates a new vector.
+    pub const fn new(x: T, y: T) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Creates a new vector by applying a function to each component of this one.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec2<U> {
+        Vec2 { x: f(self.x), y: f(self.y) }
+    }
+
+    /// The dot product of this vector and another.
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The Manhattan (taxicab) distance between this vector and another.
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The Chebyshev (chessboard) distance between this vector and another.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// The square of the Euclidean (straight-line) distance between this vector and another.
+    pub fn dist_sq(self, other: Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        dx * dx + dy * dy
+    }
+
+    /// Takes the [Euclidean remainder][PrimInt::rem_euclid] of each component with the matching component of `rhs`.
+    ///
+    /// This is useful for wrapping a position around the edges of a `rhs.x` × `rhs.y` area.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Vec2 {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+
+    /// Converts this vector into a grid position, if neither component is negative or too large.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+
+    /// Converts a grid position into a vector, if both components fit into `T`.
+    pub fn from_pos(pos: impl GridIndex) -> Option<Self> {
+        Some(Vec2 {
+            x: T::from_usize(pos.x())?,
+            y: T::from_usize(pos.y())?,
+        })
+    }
+}
+
+impl<T: Integer> Vec2<T> {
+    /// The _z_ component of the cross product of this vector and another (if both were extended into three dimensions
+    /// with `z = 0`).
+    ///
+    /// This is positive when `other` is counter-clockwise from `self` (in a _y_-up coordinate system), negative when
+    /// it's clockwise, and zero when the two are parallel.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: PrimInt> Vec3<T> {
+    /// The zero vector.
+    pub const ZERO: Self = Vec3 { x: T::ZERO, y: T::ZERO, z: T::ZERO };
+
+    /// Creates a new vector.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    /// Creates a new vector by applying a function to each component of this one.
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3 { x: f(self.x), y: f(self.y), z: f(self.z) }
+    }
+
+    /// The dot product of this vector and another.
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The Manhattan (taxicab) distance between this vector and another.
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// The Chebyshev (chessboard) distance between this vector and another.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y)).max(abs_diff(self.z, other.z))
+    }
+
+    /// The square of the Euclidean (straight-line) distance between this vector and another.
+    pub fn dist_sq(self, other: Self) -> T {
+        let (dx, dy, dz) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y), abs_diff(self.z, other.z));
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Takes the [Euclidean remainder][PrimInt::rem_euclid] of each component with the matching component of `rhs`.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Vec3 {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+            z: self.z.rem_euclid(rhs.z),
+        }
+    }
+}
+
+impl<T: Integer> Vec3<T> {
+    /// The cross product of this vector and another.
+    pub fn cross(self, other: Self) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+impl<T: PrimInt> FromStr for Vec2<T> {
+    type Err = ParseVecError;
+
+    /// Parses a vector from a string like `"x,y"` or `"p=x,y"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_components(s).map(Vec2::from)
+    }
+}
+
+impl<T: PrimInt> FromStr for Vec3<T> {
+    type Err = ParseVecError;
+
+    /// Parses a vector from a string like `"x,y,z"` or `"p=x,y,z"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_components(s).map(Vec3::from)
+    }
+}
+
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = format!("{},{}", self.x, self.y);
+        f.pad(&str)
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = format!("{},{},{}", self.x, self.y, self.z);
+        f.pad(&str)
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    fn from(v: Vec2<T>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for Vec2<T> {
+    fn from(Point { x, y }: Point<T>) -> Self {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for Point<T> {
+    fn from(Vec2 { x, y }: Vec2<T>) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    fn from(v: Vec3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3 { x, y, z }
+    }
+}
+
+/// A vector can be used to index a [`Grid`][crate::Grid] directly.
+///
+/// # Panics
+///
+/// Indexing panics if either component is negative (or too large to fit into a `usize`). Use [`Vec2::to_pos`] to check
+/// first.
+impl<T: PrimInt> GridIndex for Vec2<T> {
+    fn x(&self) -> usize {
+        self.x.to_usize().expect("vector x-component should be a valid grid index")
+    }
+
+    fn y(&self) -> usize {
+        self.y.to_usize().expect("vector y-component should be a valid grid index")
+    }
+
+    fn from_xy(x: usize, y: usize) -> Self {
+        Vec2::from_pos((x, y)).expect("grid index should fit into vector components")
+    }
+}
+
+impl<T: PrimInt> Sum for Vec2<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec2::ZERO, |a, b| Vec2::new(a.x + b.x, a.y + b.y))
+    }
+}
+
+impl<T: PrimInt> Sum for Vec3<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vec3::ZERO, |a, b| Vec3::new(a.x + b.x, a.y + b.y, a.z + b.z))
+    }
+}
+
+// [FIXME] `auto_ops` can't generate generic impls yet, so the operators have to be stamped out once per integer type.
+macro_rules! impl_vec_ops {
+    (@one $vec:ident { $($f:ident),+ } $t:ty) => {
+        impl_op_ex!(+ |a: &$vec<$t>, b: &$vec<$t>| -> $vec<$t> { $vec { $($f: a.$f + b.$f),+ } });
+        impl_op_ex!(- |a: &$vec<$t>, b: &$vec<$t>| -> $vec<$t> { $vec { $($f: a.$f - b.$f),+ } });
+        impl_op_ex!(+= |a: &mut $vec<$t>, b: &$vec<$t>| { $(a.$f += b.$f;)+ });
+        impl_op_ex!(-= |a: &mut $vec<$t>, b: &$vec<$t>| { $(a.$f -= b.$f;)+ });
+
+        impl_op_ex_commutative!(* |a: &$vec<$t>, k: &$t| -> $vec<$t> { $vec { $($f: a.$f * k),+ } });
+        impl_op_ex!(/ |a: &$vec<$t>, k: &$t| -> $vec<$t> { $vec { $($f: a.$f / k),+ } });
+        impl_op_ex!(% |a: &$vec<$t>, k: &$t| -> $vec<$t> { $vec { $($f: a.$f % k),+ } });
+        impl_op_ex!(*= |a: &mut $vec<$t>, k: &$t| { $(a.$f *= k;)+ });
+        impl_op_ex!(/= |a: &mut $vec<$t>, k: &$t| { $(a.$f /= k;)+ });
+        impl_op_ex!(%= |a: &mut $vec<$t>, k: &$t| { $(a.$f %= k;)+ });
+    };
+    (@neg $vec:ident { $($f:ident),+ } $t:ty) => {
+        impl_op_ex!(- |a: &$vec<$t>| -> $vec<$t> { $vec { $($f: -a.$f),+ } });
+    };
+    ($vec:ident $fields:tt; unsigned: $($u:ty),*; signed: $($s:ty),*) => {
+        $(impl_vec_ops!(@one $vec $fields $u);)*
+        $(impl_vec_ops!(@one $vec $fields $s);)*
+        $(impl_vec_ops!(@neg $vec $fields $s);)*
+    };
+}
+
+impl_vec_ops!(Vec2 { x, y }; unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+impl_vec_ops!(Vec3 { x, y, z }; unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);